@@ -0,0 +1,484 @@
+// --- CULTURE ASSESSMENT ---
+// A questionnaire (assessment.json, compiled in) whose questions describe symptoms and
+// point at ritual `category` values and `bug_fixed` themes. A team's answers are turned
+// into per-category pain scores and a prioritized adoption plan, and every result is
+// stored in ASSESSMENTS_TABLE so later runs can be compared with earlier ones.
+
+use axum::{
+    extract::{rejection::JsonRejection, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use colored::*;
+use redb::{Database, ReadableTable, TableDefinition};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::{BufRead, Write};
+use std::sync::Arc;
+
+use crate::error::KernelError;
+use crate::{load_rituals, Ritual, RitualFilter};
+
+pub const QUESTIONNAIRE: &str = include_str!("../assessment.json");
+
+// (team, sequence) -> AssessmentResult JSON; the sequence is taken_at in milliseconds,
+// bumped past the team's last key so two submissions in the same millisecond both land
+const ASSESSMENTS_TABLE: TableDefinition<(&str, i64), &str> = TableDefinition::new("assessments");
+
+pub const DEFAULT_TEAM: &str = "default";
+
+// Priority thresholds (0-100) for the adoption plan; lower scores are left out
+const TIERS: &[(u32, &str)] = &[(75, "adopt now"), (50, "adopt next"), (25, "consider")];
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Questionnaire {
+    version: u32,
+    title: String,
+    scale: Scale,
+    questions: Vec<Question>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Scale {
+    min: u8,
+    max: u8,
+    labels: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Question {
+    id: String,
+    text: String,
+    category: String,
+    // Matched case-insensitively against `bug_fixed`
+    themes: Vec<String>,
+}
+
+impl Question {
+    fn addresses(&self, ritual: &Ritual) -> bool {
+        let bug = ritual.bug_fixed.to_lowercase();
+        ritual.category.eq_ignore_ascii_case(&self.category)
+            || self.themes.iter().any(|t| bug.contains(&t.to_lowercase()))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Submission {
+    team: Option<String>,
+    answers: BTreeMap<String, u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AssessmentResult {
+    team: String,
+    taken_at: DateTime<Utc>,
+    questionnaire_version: u32,
+    answers: BTreeMap<String, u8>,
+    // 0 (no pain) to 100 (constant pain), per ritual category
+    category_scores: BTreeMap<String, u32>,
+    overall: u32,
+    // Category score movement since this team's previous assessment
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    change_since_last: Option<BTreeMap<String, i64>>,
+    plan: Vec<PlanItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PlanItem {
+    id: String,
+    name: String,
+    priority: u32,
+    tier: String,
+    // Question ids this ritual answers, most painful first
+    addresses: Vec<String>,
+}
+
+pub fn questionnaire() -> Result<Questionnaire, KernelError> {
+    Ok(serde_json::from_str(QUESTIONNAIRE)?)
+}
+
+fn percent(value: f64) -> u32 {
+    (value * 100.0).round() as u32
+}
+
+fn validate(
+    questionnaire: &Questionnaire,
+    answers: &BTreeMap<String, u8>,
+) -> Result<(), KernelError> {
+    let scale = &questionnaire.scale;
+    let mut problems = Vec::new();
+    for question in &questionnaire.questions {
+        match answers.get(&question.id) {
+            None => problems.push(format!("{} is unanswered", question.id)),
+            Some(a) if !(scale.min..=scale.max).contains(a) => problems.push(format!(
+                "{}: answer {} is outside {}-{}",
+                question.id, a, scale.min, scale.max
+            )),
+            Some(_) => {}
+        }
+    }
+    for id in answers.keys() {
+        if !questionnaire.questions.iter().any(|q| &q.id == id) {
+            problems.push(format!("{} is not a question in this assessment", id));
+        }
+    }
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(KernelError::Validation(problems))
+    }
+}
+
+// Scores validated answers against the stored rituals
+fn score(
+    questionnaire: &Questionnaire,
+    rituals: &[Ritual],
+    team: &str,
+    answers: BTreeMap<String, u8>,
+) -> AssessmentResult {
+    let scale = &questionnaire.scale;
+    let severity = |q: &Question| {
+        let answer = answers.get(&q.id).copied().unwrap_or(scale.min);
+        f64::from(answer - scale.min) / f64::from((scale.max - scale.min).max(1))
+    };
+
+    let mut by_category: BTreeMap<String, Vec<f64>> = BTreeMap::new();
+    for question in &questionnaire.questions {
+        by_category
+            .entry(question.category.clone())
+            .or_default()
+            .push(severity(question));
+    }
+    let category_scores: BTreeMap<String, u32> = by_category
+        .iter()
+        .map(|(category, values)| {
+            (
+                category.clone(),
+                percent(values.iter().sum::<f64>() / values.len() as f64),
+            )
+        })
+        .collect();
+    let all: Vec<f64> = questionnaire.questions.iter().map(severity).collect();
+    let overall = percent(all.iter().sum::<f64>() / all.len().max(1) as f64);
+
+    // A ritual is as urgent as the worst symptom it addresses
+    let mut plan: Vec<PlanItem> = Vec::new();
+    for ritual in rituals {
+        let mut linked: Vec<(&Question, f64)> = questionnaire
+            .questions
+            .iter()
+            .filter(|q| q.addresses(ritual))
+            .map(|q| (q, severity(q)))
+            .collect();
+        linked.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        let Some(&(_, worst)) = linked.first() else {
+            continue;
+        };
+        let priority = percent(worst);
+        let Some(&(_, tier)) = TIERS.iter().find(|(min, _)| priority >= *min) else {
+            continue;
+        };
+        plan.push(PlanItem {
+            id: ritual.id.clone(),
+            name: ritual.name.clone(),
+            priority,
+            tier: tier.to_string(),
+            addresses: linked.iter().map(|(q, _)| q.id.clone()).collect(),
+        });
+    }
+    plan.sort_by(|a, b| {
+        b.priority
+            .cmp(&a.priority)
+            .then_with(|| b.addresses.len().cmp(&a.addresses.len()))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+
+    AssessmentResult {
+        team: team.to_string(),
+        taken_at: Utc::now(),
+        questionnaire_version: questionnaire.version,
+        answers,
+        category_scores,
+        overall,
+        change_since_last: None,
+        plan,
+    }
+}
+
+// Validates, scores and (optionally) stores one set of answers
+pub fn assess(
+    db: &Database,
+    team: &str,
+    answers: BTreeMap<String, u8>,
+    save: bool,
+) -> Result<AssessmentResult, KernelError> {
+    let questionnaire = questionnaire()?;
+    if team.trim().is_empty() {
+        return Err(KernelError::Validation(vec![
+            "team must not be empty".to_string()
+        ]));
+    }
+    validate(&questionnaire, &answers)?;
+
+    let rituals = load_rituals(db, &RitualFilter::default())?;
+    let mut result = score(&questionnaire, &rituals, team, answers);
+
+    if !save {
+        let previous = history(db, Some(team))?.pop();
+        compare_with(&mut result, previous.as_ref());
+        return Ok(result);
+    }
+
+    // The previous result and the new sequence come from the same snapshot
+    let write_txn = db.begin_write()?;
+    {
+        let mut table = write_txn.open_table(ASSESSMENTS_TABLE)?;
+        let last = table
+            .range((team, i64::MIN)..=(team, i64::MAX))?
+            .next_back()
+            .transpose()?
+            .map(|(key, value)| (key.value().1, value.value().to_string()));
+        let previous: Option<AssessmentResult> = last
+            .as_ref()
+            .map(|(_, json)| serde_json::from_str(json))
+            .transpose()?;
+        compare_with(&mut result, previous.as_ref());
+
+        let now = result.taken_at.timestamp_millis();
+        let seq = last.map_or(now, |(last, _)| now.max(last + 1));
+        let json = serde_json::to_string(&result)?;
+        table.insert((team, seq), json.as_str())?;
+    }
+    write_txn.commit()?;
+    Ok(result)
+}
+
+// Per-category score change since the team's previous assessment
+fn compare_with(result: &mut AssessmentResult, previous: Option<&AssessmentResult>) {
+    let Some(previous) = previous else {
+        return;
+    };
+    result.change_since_last = Some(
+        result
+            .category_scores
+            .iter()
+            .map(|(category, now)| {
+                let before = previous.category_scores.get(category).copied().unwrap_or(0);
+                (category.clone(), i64::from(*now) - i64::from(before))
+            })
+            .collect(),
+    );
+}
+
+// Stored results, oldest first; all teams when `team` is None
+pub fn history(db: &Database, team: Option<&str>) -> Result<Vec<AssessmentResult>, KernelError> {
+    let read_txn = db.begin_read()?;
+    let table = match read_txn.open_table(ASSESSMENTS_TABLE) {
+        Ok(t) => t,
+        Err(redb::TableError::TableDoesNotExist(_)) => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut results: Vec<AssessmentResult> = Vec::new();
+    for item in table.iter()? {
+        let (key, value) = item?;
+        if team.is_some_and(|t| t != key.value().0) {
+            continue;
+        }
+        results.push(serde_json::from_str(value.value())?);
+    }
+    results.sort_by_key(|r| r.taken_at);
+    Ok(results)
+}
+
+// --- CLI: `culture-kernel assess` ---
+pub fn assess_cli(
+    db: &Arc<Database>,
+    team: &str,
+    show_history: bool,
+    save: bool,
+) -> anyhow::Result<()> {
+    if show_history {
+        return print_history(&history(db, Some(team))?, team);
+    }
+
+    let questionnaire = questionnaire()?;
+    let scale = &questionnaire.scale;
+    println!(
+        "{}",
+        format!(" {} ", questionnaire.title.to_uppercase())
+            .on_blue()
+            .white()
+            .bold()
+    );
+    println!(
+        "How often is each statement true for team '{}'?",
+        team.cyan()
+    );
+    for (i, label) in scale.labels.iter().enumerate() {
+        println!("  {} = {}", scale.min as usize + i, label);
+    }
+    println!();
+
+    let stdin = std::io::stdin();
+    let mut lines = stdin.lock().lines();
+    let mut answers = BTreeMap::new();
+    let total = questionnaire.questions.len();
+    for (i, question) in questionnaire.questions.iter().enumerate() {
+        println!(
+            "{} {}",
+            format!("[{}/{}]", i + 1, total).dimmed(),
+            question.text
+        );
+        let answer = loop {
+            print!("  {}-{}> ", scale.min, scale.max);
+            std::io::stdout().flush()?;
+            let Some(line) = lines.next().transpose()? else {
+                anyhow::bail!("assessment aborted: no answer for {}", question.id);
+            };
+            match line.trim().parse::<u8>() {
+                Ok(a) if (scale.min..=scale.max).contains(&a) => break a,
+                _ => println!(
+                    "  {}",
+                    format!("Please answer {} to {}.", scale.min, scale.max).yellow()
+                ),
+            }
+        };
+        answers.insert(question.id.clone(), answer);
+    }
+
+    let result = assess(db, team, answers, save)?;
+    println!();
+    print_result(&result);
+    if save {
+        println!(
+            "{} as '{}' at {}",
+            "Saved".green().bold(),
+            team,
+            result.taken_at.to_rfc3339()
+        );
+    }
+    Ok(())
+}
+
+fn bar(score: u32) -> String {
+    let filled = (score as usize).div_ceil(10);
+    let bar = format!("{}{}", "█".repeat(filled), "░".repeat(10 - filled));
+    match score {
+        75.. => bar.red().to_string(),
+        50.. => bar.yellow().to_string(),
+        _ => bar.green().to_string(),
+    }
+}
+
+fn print_result(result: &AssessmentResult) {
+    println!("{} {}/100", "OVERALL PAIN".bold(), result.overall);
+    for (category, score) in &result.category_scores {
+        let change = result
+            .change_since_last
+            .as_ref()
+            .and_then(|c| c.get(category))
+            .map(|d| format!(" ({:+})", d))
+            .unwrap_or_default();
+        println!(
+            "  {} {:>3} {}{}",
+            bar(*score),
+            score,
+            category,
+            change.dimmed()
+        );
+    }
+
+    println!();
+    if result.plan.is_empty() {
+        println!(
+            "{}",
+            "No ritual needed right now. Keep doing what works.".green()
+        );
+        return;
+    }
+    println!("{}", "ADOPTION PLAN".bold());
+    for (rank, item) in result.plan.iter().enumerate() {
+        println!(
+            "  {}. {} {} {} [{}] addresses {}",
+            rank + 1,
+            item.tier.to_uppercase().yellow().bold(),
+            item.name.green(),
+            item.id.cyan(),
+            item.priority,
+            item.addresses.join(", ")
+        );
+    }
+}
+
+fn print_history(results: &[AssessmentResult], team: &str) -> anyhow::Result<()> {
+    if results.is_empty() {
+        println!(
+            "No stored assessments for team '{}'. Run 'culture-kernel assess' first.",
+            team
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!(" ASSESSMENT HISTORY: {} ", team)
+            .on_blue()
+            .white()
+            .bold()
+    );
+    for result in results {
+        let change = result
+            .change_since_last
+            .as_ref()
+            .map(|c| {
+                let improved = c.values().filter(|d| **d < 0).count();
+                let worse = c.values().filter(|d| **d > 0).count();
+                format!("{} categories better, {} worse", improved, worse)
+            })
+            .unwrap_or_else(|| "first assessment".to_string());
+        let top = result.plan.first().map(|p| p.id.as_str()).unwrap_or("-");
+        println!(
+            "  {}  overall {:>3}  top: {:<24} {}",
+            result.taken_at.format("%Y-%m-%d %H:%M"),
+            result.overall,
+            top.cyan(),
+            change.dimmed()
+        );
+    }
+    Ok(())
+}
+
+// --- API: /assessment ---
+
+// GET /assessment
+pub async fn api_questionnaire() -> Result<Response, KernelError> {
+    Ok(Json(questionnaire()?).into_response())
+}
+
+// POST /assessment
+pub async fn api_submit(
+    State(db): State<Arc<Database>>,
+    body: Result<Json<Submission>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(submission) = body?;
+    let team = submission.team.unwrap_or_else(|| DEFAULT_TEAM.to_string());
+    let result = assess(&db, &team, submission.answers, true)?;
+    Ok((StatusCode::CREATED, Json(result)).into_response())
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HistoryParams {
+    team: Option<String>,
+}
+
+// GET /assessment/results?team=...
+pub async fn api_history(
+    State(db): State<Arc<Database>>,
+    Query(params): Query<HistoryParams>,
+) -> Result<Response, KernelError> {
+    Ok(Json(history(&db, params.team.as_deref())?).into_response())
+}
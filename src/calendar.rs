@@ -0,0 +1,312 @@
+// --- ICALENDAR EXPORT ---
+// RFC 5545 feeds so teams can subscribe to rituals from any calendar client.
+// Each ritual becomes one recurring VEVENT built from its stored `schedule`; callers
+// may override the start and cadence (e.g. to try a ritual that has no schedule yet).
+// The description carries the modern_script steps and ethical_guardrails, and the
+// schedule's lead time becomes a VALARM reminder.
+
+use axum::{
+    extract::{Path as UrlPath, Query, State},
+    http::{header, HeaderValue},
+    response::{IntoResponse, Response},
+};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use clap::Subcommand;
+use colored::*;
+use redb::Database;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::error::KernelError;
+use crate::recurrence;
+use crate::render::step_title;
+use crate::schedule::Schedule;
+use crate::{load_ritual, load_rituals, Ritual, RitualFilter};
+
+const PRODID: &str = "-//Culture Kernel//Ritual Calendar//EN";
+// Used when neither the request nor the stored schedule says otherwise
+const DEFAULT_HOUR: u32 = 9;
+const DEFAULT_DURATION_MINUTES: u32 = 60;
+
+// Start date and cadence chosen by the caller; unset fields come from the stored schedule
+#[derive(Debug, Default, Deserialize)]
+pub struct Overrides {
+    // "2026-11-02" or "2026-11-02T10:00:00" (UTC)
+    pub start: Option<String>,
+    // e.g. "FREQ=WEEKLY;INTERVAL=2"
+    pub rrule: Option<String>,
+    pub duration_minutes: Option<u32>,
+}
+
+// "2026-11-02" keeps the time of day from the schedule; a full timestamp replaces it
+fn parse_start(value: &str) -> Option<(NaiveDate, Option<NaiveTime>)> {
+    let value = value.trim().trim_end_matches('Z');
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Some((date, None));
+    }
+    ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M"]
+        .iter()
+        .find_map(|f| NaiveDateTime::parse_from_str(value, f).ok())
+        .map(|dt| (dt.date(), Some(dt.time())))
+}
+
+impl Overrides {
+    // The schedule a ritual is exported with, or why it cannot be exported
+    fn apply(&self, ritual: &Ritual) -> Result<Schedule, String> {
+        let stored = ritual.schedule.as_ref();
+
+        let start = match self.start.as_deref() {
+            Some(text) => {
+                let (date, time) = parse_start(text)
+                    .ok_or_else(|| format!("start '{}' is not YYYY-MM-DD or YYYY-MM-DDTHH:MM[:SS]", text))?;
+                let time = time
+                    .or(stored.map(|s| s.start.time()))
+                    .unwrap_or_else(|| NaiveTime::from_hms_opt(DEFAULT_HOUR, 0, 0).unwrap_or_default());
+                date.and_time(time)
+            }
+            None => match stored {
+                Some(s) => s.start,
+                None => return Err(format!("{} has no schedule; pass a start and rrule", ritual.id)),
+            },
+        };
+        let rrule = match (self.rrule.as_deref(), stored) {
+            (Some(rrule), _) => rrule.trim().trim_start_matches("RRULE:").to_ascii_uppercase(),
+            (None, Some(s)) => s.rrule.clone(),
+            (None, None) => return Err(format!("{} has no schedule; pass an rrule", ritual.id)),
+        };
+
+        let schedule = Schedule {
+            rrule,
+            start,
+            duration_minutes: self
+                .duration_minutes
+                .or(stored.map(|s| s.duration_minutes))
+                .unwrap_or(DEFAULT_DURATION_MINUTES),
+            lead_time_days: stored.map(|s| s.lead_time_days).unwrap_or(0),
+        };
+        match schedule.validate().first() {
+            Some(problem) => Err(problem.clone()),
+            None => Ok(schedule),
+        }
+    }
+}
+
+// Builds the feed, collecting every ritual that cannot be scheduled into one 422
+pub fn feed(name: &str, rituals: &[Ritual], overrides: &Overrides) -> Result<String, KernelError> {
+    let mut problems = Vec::new();
+    let mut events = Vec::new();
+    for ritual in rituals {
+        match overrides.apply(ritual) {
+            Ok(schedule) => events.push(event(ritual, &schedule)),
+            Err(problem) => problems.push(problem),
+        }
+    }
+    if !problems.is_empty() {
+        return Err(KernelError::Validation(problems));
+    }
+
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{}", PRODID),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        format!("X-WR-CALNAME:{}", escape_text(name)),
+    ];
+    lines.extend(events.into_iter().flatten());
+    lines.push("END:VCALENDAR".to_string());
+
+    Ok(lines.iter().map(|l| fold(l)).collect())
+}
+
+fn event(ritual: &Ritual, schedule: &Schedule) -> Vec<String> {
+    let mut lines = vec![
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{}@culture-kernel", ritual.id),
+        format!("DTSTAMP:{}", Utc::now().format("%Y%m%dT%H%M%SZ")),
+        format!("DTSTART:{}", schedule.start.format("%Y%m%dT%H%M%SZ")),
+        format!("DURATION:PT{}M", schedule.duration_minutes),
+        format!("RRULE:{}", recurrence::utc_until(&schedule.rrule)),
+        format!("SUMMARY:{}", escape_text(&ritual.name)),
+        format!("DESCRIPTION:{}", escape_text(&description(ritual))),
+        format!("CATEGORIES:{}", escape_text(&ritual.category)),
+    ];
+    if schedule.lead_time_days > 0 {
+        lines.extend([
+            "BEGIN:VALARM".to_string(),
+            "ACTION:DISPLAY".to_string(),
+            format!("TRIGGER:-P{}D", schedule.lead_time_days),
+            format!("DESCRIPTION:{}", escape_text(&format!("Prepare for {}", ritual.name))),
+            "END:VALARM".to_string(),
+        ]);
+    }
+    lines.push("END:VEVENT".to_string());
+    lines
+}
+
+fn description(ritual: &Ritual) -> String {
+    let mut text = format!(
+        "{} ({})\nBug fixed: {}\nMechanism: {}\n\nModern script:\n",
+        ritual.name, ritual.origin_culture, ritual.bug_fixed, ritual.mechanism
+    );
+    // Sorted so a re-downloaded feed only changes when the ritual does
+    let mut steps: Vec<_> = ritual.modern_script.iter().collect();
+    steps.sort();
+    for (key, value) in steps {
+        text.push_str(&format!("- {}: {}\n", step_title(key), value));
+    }
+    text.push_str("\nEthical guardrails:\n");
+    for guardrail in &ritual.ethical_guardrails {
+        text.push_str(&format!("- {}\n", guardrail));
+    }
+    text.trim_end().to_string()
+}
+
+// TEXT values escape backslashes, ';', ',' and newlines (RFC 5545 §3.3.11)
+fn escape_text(value: &str) -> String {
+    let mut output = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => output.push_str("\\\\"),
+            ';' => output.push_str("\\;"),
+            ',' => output.push_str("\\,"),
+            '\n' => output.push_str("\\n"),
+            '\r' => {}
+            _ => output.push(c),
+        }
+    }
+    output
+}
+
+// Content lines are at most 75 octets, continued with CRLF + space, never inside a UTF-8 character
+fn fold(line: &str) -> String {
+    let mut output = String::with_capacity(line.len() + line.len() / 70 * 3 + 2);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            output.push_str("\r\n ");
+            width = 1;
+        }
+        output.push(c);
+        width += c.len_utf8();
+    }
+    output.push_str("\r\n");
+    output
+}
+
+// Rituals by id in the order given, or every scheduled ritual when no ids are given
+fn select(db: &Database, ids: &[String]) -> Result<Vec<Ritual>, KernelError> {
+    if ids.is_empty() {
+        let mut rituals = load_rituals(db, &RitualFilter::default())?;
+        rituals.retain(|r| r.schedule.is_some());
+        return Ok(rituals);
+    }
+    ids.iter()
+        .map(|id| load_ritual(db, id)?.ok_or_else(|| KernelError::not_found("ritual", id)))
+        .collect()
+}
+
+// --- CLI: `culture-kernel calendar` ---
+
+#[derive(Subcommand, Debug)]
+pub enum CalendarCommand {
+    /// Write an iCalendar (.ics) feed for the given rituals (all scheduled ones by default)
+    Export {
+        /// Ritual ids, e.g. RWANDA_21_UMUGANDA
+        ids: Vec<String>,
+        /// First occurrence (YYYY-MM-DD or YYYY-MM-DDTHH:MM, UTC) instead of the stored one
+        #[arg(long)]
+        start: Option<String>,
+        /// Recurrence rule instead of the stored one, e.g. "FREQ=WEEKLY;INTERVAL=2"
+        #[arg(long)]
+        rrule: Option<String>,
+        /// Event length in minutes instead of the stored one
+        #[arg(long)]
+        duration_minutes: Option<u32>,
+        /// Destination file; prints to stdout when omitted
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Overwrite the destination if it exists
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+pub fn calendar_cli(db: &Arc<Database>, command: &CalendarCommand) -> anyhow::Result<()> {
+    match command {
+        CalendarCommand::Export { ids, start, rrule, duration_minutes, output, force } => {
+            let overrides = Overrides {
+                start: start.clone(),
+                rrule: rrule.clone(),
+                duration_minutes: *duration_minutes,
+            };
+            let rituals = select(db, ids)?;
+            let ics = feed("Culture Kernel Rituals", &rituals, &overrides)?;
+            write_ics(output.as_deref(), *force, &ics, rituals.len())?;
+        }
+    }
+    Ok(())
+}
+
+fn write_ics(output: Option<&Path>, force: bool, ics: &str, count: usize) -> anyhow::Result<()> {
+    let Some(path) = output else {
+        print!("{}", ics);
+        return Ok(());
+    };
+
+    if path.exists() && !force {
+        anyhow::bail!("'{}' already exists (use --force to overwrite)", path.display());
+    }
+    std::fs::write(path, ics).map_err(|e| anyhow::anyhow!("cannot write '{}': {}", path.display(), e))?;
+    println!("{} {} ritual(s) to {}", "Exported".green().bold(), count, path.display());
+    Ok(())
+}
+
+// --- API: calendar feeds ---
+
+fn ics_response(ics: String) -> Response {
+    let mut response = ics.into_response();
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static("text/calendar; charset=utf-8"),
+    );
+    response
+}
+
+// GET /rituals/{id}/calendar.ics?start=&rrule=&duration_minutes=
+pub async fn api_ritual_feed(
+    State(db): State<Arc<Database>>,
+    UrlPath(id): UrlPath<String>,
+    Query(overrides): Query<Overrides>,
+) -> Result<Response, KernelError> {
+    let ritual = load_ritual(&db, &id)?.ok_or_else(|| KernelError::not_found("ritual", &id))?;
+    let ics = feed(&ritual.name, std::slice::from_ref(&ritual), &overrides)?;
+    Ok(ics_response(ics))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FeedParams {
+    // Comma-separated ritual ids
+    ids: Option<String>,
+}
+
+// GET /calendar.ics?ids=RWANDA_21_UMUGANDA,YORUBA_05_ESUSU
+pub async fn api_feed(
+    State(db): State<Arc<Database>>,
+    Query(params): Query<FeedParams>,
+    Query(overrides): Query<Overrides>,
+) -> Result<Response, KernelError> {
+    let ids: Vec<String> = params
+        .ids
+        .as_deref()
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(str::to_string)
+        .collect();
+    let rituals = select(&db, &ids)?;
+    let ics = feed("Culture Kernel Rituals", &rituals, &overrides)?;
+    Ok(ics_response(ics))
+}
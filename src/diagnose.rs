@@ -0,0 +1,248 @@
+// --- DIAGNOSIS ---
+// Maps a free-text description of a team dysfunction onto the rituals whose
+// `bug_fixed`, `category` and `mechanism` describe it best. Scoring is local and
+// deterministic: IDF-weighted term overlap with light stemming, prefix matching
+// and a small synonym table that translates everyday words into the library's vocabulary.
+
+use axum::{
+    extract::{rejection::JsonRejection, State},
+    response::{IntoResponse, Response},
+    Json,
+};
+use redb::Database;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use crate::error::KernelError;
+use crate::{load_rituals, search, Ritual, RitualFilter};
+
+const DEFAULT_LIMIT: usize = 3;
+const MAX_LIMIT: usize = 20;
+
+// The bug a ritual fixes matters most; the mechanism is only a hint
+const FIELD_WEIGHTS: &[(&str, f64)] = &[("bug_fixed", 3.0), ("category", 1.5), ("mechanism", 1.0)];
+
+// A synonym match counts for less than the user's own word
+const SYNONYM_WEIGHT: f64 = 0.6;
+
+// Stems this long may match longer words that start with them ("hoard" ~ "hoarding")
+const MIN_PREFIX: usize = 5;
+
+// Words that describe almost every complaint and carry no signal
+const FILLER: &[&str] = &[
+    "our", "we", "they", "their", "them", "people", "team", "teams", "always", "never", "keep",
+    "too", "very", "much", "many", "lot", "get", "gets", "when", "all", "each", "there", "this",
+    "that", "who", "no", "not", "do", "does", "don", "after", "during",
+];
+
+// Everyday word -> library vocabulary
+const SYNONYMS: &[(&str, &[&str])] = &[
+    ("outage", &["incident", "crisis"]),
+    ("downtime", &["incident", "crisis"]),
+    ("firefighting", &["incident", "crisis"]),
+    ("stress", &["panic", "emotional"]),
+    ("chaos", &["panic", "volatility"]),
+    ("lonely", &["isolation"]),
+    ("newcomer", &["hire", "onboarding"]),
+    ("onboard", &["hire", "onboarding"]),
+    ("attrition", &["exit", "stagnation"]),
+    ("turnover", &["exit", "stagnation"]),
+    ("quit", &["exit", "stagnation"]),
+    ("bored", &["stagnation"]),
+    ("side", &["moonlighting"]),
+    ("legacy", &["debt", "rot"]),
+    ("cleanup", &["debt", "rot", "maintenance"]),
+    ("tape", &["bureaucracy"]),
+    ("paperwork", &["bureaucracy"]),
+    ("blame", &["accountability"]),
+    ("ownership", &["accountability"]),
+    ("goal", &["accountability", "performance"]),
+    ("transparency", &["opacity", "accountability"]),
+    ("api", &["contract", "microservice"]),
+    ("inconsistent", &["standardization", "sprawl"]),
+    ("mentor", &["sponsorship", "mentorship"]),
+    ("manager", &["hierarchy"]),
+    ("leadership", &["hierarchy"]),
+    ("afraid", &["aversion", "risk"]),
+    ("cautious", &["aversion", "risk"]),
+    ("funding", &["budget", "allocation"]),
+    ("headcount", &["resource"]),
+    ("poach", &["theft", "exit"]),
+];
+
+#[derive(Debug, Deserialize)]
+pub struct DiagnoseRequest {
+    description: String,
+    limit: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Diagnosis {
+    description: String,
+    recommendations: Vec<Recommendation>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Recommendation {
+    id: String,
+    name: String,
+    bug_fixed: String,
+    score: f64,
+    matches: Vec<TermMatch>,
+    explanation: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TermMatch {
+    // The user's word
+    term: String,
+    // Library word it was translated to, for synonym matches
+    #[serde(skip_serializing_if = "Option::is_none")]
+    via: Option<String>,
+    field: &'static str,
+    // The word in the ritual that matched
+    matched: String,
+}
+
+// Plural folding only; prefix matching covers the other inflections
+fn stem(word: &str) -> String {
+    let word = word.to_lowercase();
+    if let Some(base) = word.strip_suffix("ies").filter(|b| b.len() >= 3) {
+        return format!("{}y", base);
+    }
+    if word.len() > 3 && word.ends_with('s') && !word.ends_with("ss") && !word.ends_with("is") {
+        return word[..word.len() - 1].to_string();
+    }
+    word
+}
+
+fn related(a: &str, b: &str) -> bool {
+    let (short, long) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    short == long || (short.len() >= MIN_PREFIX && long.starts_with(short))
+}
+
+// A ritual's weighted fields as (field, stem, original word)
+fn profile(ritual: &Ritual) -> Vec<(&'static str, String, String)> {
+    let mut words = Vec::new();
+    for &(field, _) in FIELD_WEIGHTS {
+        let text = match field {
+            "bug_fixed" => &ritual.bug_fixed,
+            "category" => &ritual.category,
+            _ => &ritual.mechanism,
+        };
+        for word in search::words(text) {
+            words.push((field, stem(word), word.to_string()));
+        }
+    }
+    words
+}
+
+fn field_weight(field: &str) -> f64 {
+    FIELD_WEIGHTS.iter().find(|(f, _)| *f == field).map(|(_, w)| *w).unwrap_or(1.0)
+}
+
+// First word per field of the profile related to `stem`
+fn matches_in(profile: &[(&'static str, String, String)], stem: &str) -> Vec<(&'static str, String)> {
+    let mut seen = BTreeSet::new();
+    profile
+        .iter()
+        .filter(|(field, candidate, _)| related(candidate, stem) && seen.insert(*field))
+        .map(|(field, _, word)| (*field, word.clone()))
+        .collect()
+}
+
+pub fn diagnose(rituals: &[Ritual], description: &str, limit: usize) -> Result<Vec<Recommendation>, KernelError> {
+    let terms: BTreeSet<String> = search::words(description)
+        .into_iter()
+        .map(stem)
+        .filter(|t| !FILLER.contains(&t.as_str()))
+        .collect();
+    if terms.is_empty() {
+        return Err(KernelError::Validation(vec![
+            "description must mention at least one meaningful word".to_string(),
+        ]));
+    }
+
+    let profiles: Vec<_> = rituals.iter().map(profile).collect();
+    let total = rituals.len() as f64;
+    let idf = |stem: &str| {
+        let df = profiles.iter().filter(|p| p.iter().any(|(_, s, _)| related(s, stem))).count() as f64;
+        (1.0 + total / (df + 1.0)).ln()
+    };
+
+    let mut recommendations = Vec::new();
+    for (ritual, profile) in rituals.iter().zip(&profiles) {
+        let mut score = 0.0;
+        let mut found = Vec::new();
+
+        for term in &terms {
+            let direct = matches_in(profile, term);
+            if !direct.is_empty() {
+                let weight = idf(term);
+                for (field, matched) in direct {
+                    score += weight * field_weight(field);
+                    found.push(TermMatch { term: term.clone(), via: None, field, matched });
+                }
+                continue;
+            }
+
+            // Only translate words the ritual does not already share with the user
+            let synonyms = SYNONYMS
+                .iter()
+                .filter(|(word, _)| related(&stem(word), term))
+                .flat_map(|(_, targets)| targets.iter());
+            for target in synonyms {
+                let target_stem = stem(target);
+                let weight = idf(&target_stem) * SYNONYM_WEIGHT;
+                for (field, matched) in matches_in(profile, &target_stem) {
+                    if found.iter().any(|m: &TermMatch| m.term == *term && m.field == field) {
+                        continue;
+                    }
+                    score += weight * field_weight(field);
+                    found.push(TermMatch { term: term.clone(), via: Some(target.to_string()), field, matched });
+                }
+            }
+        }
+
+        if found.is_empty() {
+            continue;
+        }
+        recommendations.push(Recommendation {
+            id: ritual.id.clone(),
+            name: ritual.name.clone(),
+            bug_fixed: ritual.bug_fixed.clone(),
+            score: (score * 1000.0).round() / 1000.0,
+            explanation: explain(&found),
+            matches: found,
+        });
+    }
+
+    recommendations.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.id.cmp(&b.id)));
+    recommendations.truncate(limit);
+    Ok(recommendations)
+}
+
+fn explain(found: &[TermMatch]) -> String {
+    found
+        .iter()
+        .map(|m| match &m.via {
+            None => format!("\"{}\" matches \"{}\" in {}", m.term, m.matched, m.field),
+            Some(via) => format!("\"{}\" (as \"{}\") matches \"{}\" in {}", m.term, via, m.matched, m.field),
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+// --- API: POST /diagnose ---
+pub async fn api_diagnose(
+    State(db): State<Arc<Database>>,
+    body: Result<Json<DiagnoseRequest>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(request) = body?;
+    let rituals = load_rituals(&db, &RitualFilter::default())?;
+    let limit = request.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    let recommendations = diagnose(&rituals, &request.description, limit)?;
+    Ok(Json(Diagnosis { description: request.description, recommendations }).into_response())
+}
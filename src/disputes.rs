@@ -0,0 +1,617 @@
+// --- GUILD DISPUTE REGISTER ---
+// The `dispute_resolution` step of HAUSA_09_SARKIN_KASUWA: one team claims another
+// team's API breaks a contract and the guild rules on it. A dispute moves
+//   open -> hearing -> ruled -> appealed -> hearing -> ruled
+// and the kernel rejects every other transition. An arbiter (by default the sitting
+// Sarkin) must be assigned before the first hearing. Rulings are binding, always carry
+// a technical justification and are never hidden (Transparency guardrail). A ruling
+// can be appealed once; the ruling on appeal is final.
+
+use axum::{
+    extract::{rejection::JsonRejection, Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use clap::Subcommand;
+use colored::*;
+use redb::{Database, ReadableTable, TableDefinition};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::error::KernelError;
+use crate::{guild, teams};
+
+// dispute id -> Dispute JSON
+const DISPUTES_TABLE: TableDefinition<u64, &str> = TableDefinition::new("guild_disputes");
+
+const MAX_APPEALS: usize = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Open,
+    Hearing,
+    Ruled,
+    Appealed,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Open => "open",
+            Status::Hearing => "hearing",
+            Status::Ruled => "ruled",
+            Status::Appealed => "appealed",
+        }
+    }
+
+    fn can_become(self, next: Status) -> bool {
+        matches!(
+            (self, next),
+            (Status::Open, Status::Hearing)
+                | (Status::Hearing, Status::Ruled)
+                | (Status::Ruled, Status::Appealed)
+                | (Status::Appealed, Status::Hearing)
+        )
+    }
+}
+
+impl std::str::FromStr for Status {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "open" => Ok(Status::Open),
+            "hearing" => Ok(Status::Hearing),
+            "ruled" => Ok(Status::Ruled),
+            "appealed" => Ok(Status::Appealed),
+            other => Err(format!("unknown status '{}' (open, hearing, ruled, appealed)", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Transition {
+    from: Option<Status>,
+    to: Status,
+    at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Hearing {
+    arbiter: String,
+    held_at: DateTime<Utc>,
+    summary: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ruling {
+    arbiter: String,
+    ruled_at: DateTime<Utc>,
+    // One of the two parties
+    in_favor_of: String,
+    decision: String,
+    justification: String,
+    // Ruling on an appeal; cannot be appealed again
+    #[serde(default)]
+    on_appeal: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Appeal {
+    filed_by: String,
+    filed_at: DateTime<Utc>,
+    grounds: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Dispute {
+    id: u64,
+    // Team filing the complaint
+    complainant: String,
+    // Team whose API is said to break the contract
+    respondent: String,
+    // e.g. "payments-api v2: POST /charges"
+    contract: String,
+    complaint: String,
+    filed_at: DateTime<Utc>,
+    status: Status,
+    arbiter: Option<String>,
+    hearings: Vec<Hearing>,
+    rulings: Vec<Ruling>,
+    appeals: Vec<Appeal>,
+    history: Vec<Transition>,
+}
+
+impl Dispute {
+    fn transition(&mut self, next: Status) -> Result<(), KernelError> {
+        if !self.status.can_become(next) {
+            return Err(KernelError::Conflict(format!(
+                "Dispute {} is {} and cannot move to {}",
+                self.id,
+                self.status.as_str(),
+                next.as_str()
+            )));
+        }
+        self.history.push(Transition { from: Some(self.status), to: next, at: Utc::now() });
+        self.status = next;
+        Ok(())
+    }
+
+    fn is_party(&self, team: &str) -> bool {
+        self.complainant == team || self.respondent == team
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NewDispute {
+    complainant: String,
+    respondent: String,
+    contract: String,
+    complaint: String,
+    // Defaults to the sitting Sarkin
+    arbiter: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct AssignArbiter {
+    // Defaults to the sitting Sarkin
+    arbiter: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NewHearing {
+    summary: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NewRuling {
+    in_favor_of: String,
+    decision: String,
+    justification: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NewAppeal {
+    filed_by: String,
+    grounds: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct DisputeFilter {
+    status: Option<String>,
+    // Disputes where this team is either party
+    team: Option<String>,
+}
+
+fn require_text(problems: &mut Vec<String>, field: &str, value: &str) {
+    if value.trim().is_empty() {
+        problems.push(format!("{} must not be empty", field));
+    }
+}
+
+fn resolve_arbiter(db: &Database, arbiter: Option<&str>) -> Result<Option<String>, KernelError> {
+    match arbiter.map(str::trim) {
+        Some("") => Err(KernelError::Validation(vec!["arbiter must not be empty".to_string()])),
+        Some(name) => Ok(Some(name.to_string())),
+        None => guild::sitting_sarkin(db),
+    }
+}
+
+fn read_dispute(table: &impl ReadableTable<u64, &'static str>, id: u64) -> Result<Dispute, KernelError> {
+    match table.get(id)? {
+        Some(v) => Ok(serde_json::from_str(v.value())?),
+        None => Err(KernelError::not_found("dispute", id.to_string())),
+    }
+}
+
+pub fn file(db: &Database, new: NewDispute) -> Result<Dispute, KernelError> {
+    let mut problems = Vec::new();
+    require_text(&mut problems, "contract", &new.contract);
+    require_text(&mut problems, "complaint", &new.complaint);
+    if new.complainant == new.respondent {
+        problems.push("complainant and respondent must be different teams".to_string());
+    }
+    if !problems.is_empty() {
+        return Err(KernelError::Validation(problems));
+    }
+    teams::require_team(db, &new.complainant)?;
+    teams::require_team(db, &new.respondent)?;
+    let arbiter = resolve_arbiter(db, new.arbiter.as_deref())?;
+
+    let write_txn = db.begin_write()?;
+    let dispute = {
+        let mut table = write_txn.open_table(DISPUTES_TABLE)?;
+        let id = table.last()?.map(|(key, _)| key.value()).unwrap_or(0) + 1;
+        let now = Utc::now();
+        let dispute = Dispute {
+            id,
+            complainant: new.complainant,
+            respondent: new.respondent,
+            contract: new.contract.trim().to_string(),
+            complaint: new.complaint.trim().to_string(),
+            filed_at: now,
+            status: Status::Open,
+            arbiter,
+            hearings: Vec::new(),
+            rulings: Vec::new(),
+            appeals: Vec::new(),
+            history: vec![Transition { from: None, to: Status::Open, at: now }],
+        };
+        let json = serde_json::to_string(&dispute)?;
+        table.insert(id, json.as_str())?;
+        dispute
+    };
+    write_txn.commit()?;
+    Ok(dispute)
+}
+
+// Disputes in filing order
+pub fn list(db: &Database, filter: &DisputeFilter) -> Result<Vec<Dispute>, KernelError> {
+    let status = match filter.status.as_deref() {
+        Some(s) => Some(s.parse::<Status>().map_err(|e| KernelError::Validation(vec![e]))?),
+        None => None,
+    };
+
+    let read_txn = db.begin_read()?;
+    let table = match read_txn.open_table(DISPUTES_TABLE) {
+        Ok(t) => t,
+        Err(redb::TableError::TableDoesNotExist(_)) => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut disputes = Vec::new();
+    for item in table.iter()? {
+        let (_, value) = item?;
+        let dispute: Dispute = serde_json::from_str(value.value())?;
+        if status.is_none_or(|s| dispute.status == s)
+            && filter.team.as_deref().is_none_or(|t| dispute.is_party(t))
+        {
+            disputes.push(dispute);
+        }
+    }
+    Ok(disputes)
+}
+
+pub fn get(db: &Database, id: u64) -> Result<Dispute, KernelError> {
+    let read_txn = db.begin_read()?;
+    let table = match read_txn.open_table(DISPUTES_TABLE) {
+        Ok(t) => t,
+        Err(redb::TableError::TableDoesNotExist(_)) => return Err(KernelError::not_found("dispute", id.to_string())),
+        Err(e) => return Err(e.into()),
+    };
+    read_dispute(&table, id)
+}
+
+// Loads a dispute, applies `change` and stores it in one write transaction
+fn update(
+    db: &Database,
+    id: u64,
+    change: impl FnOnce(&mut Dispute) -> Result<(), KernelError>,
+) -> Result<Dispute, KernelError> {
+    let write_txn = db.begin_write()?;
+    let dispute = {
+        let mut table = write_txn.open_table(DISPUTES_TABLE)?;
+        let mut dispute = read_dispute(&table, id)?;
+        change(&mut dispute)?;
+        let json = serde_json::to_string(&dispute)?;
+        table.insert(id, json.as_str())?;
+        dispute
+    };
+    write_txn.commit()?;
+    Ok(dispute)
+}
+
+pub fn assign(db: &Database, id: u64, assignment: AssignArbiter) -> Result<Dispute, KernelError> {
+    let Some(arbiter) = resolve_arbiter(db, assignment.arbiter.as_deref())? else {
+        return Err(KernelError::Conflict(
+            "No Sarkin is in office; name an arbiter explicitly".to_string(),
+        ));
+    };
+    update(db, id, |d| {
+        // An arbiter is chosen before hearings start, or again for an appeal
+        if !matches!(d.status, Status::Open | Status::Appealed) {
+            return Err(KernelError::Conflict(format!(
+                "Dispute {} is {}; the arbiter can only change while it is open or appealed",
+                d.id,
+                d.status.as_str()
+            )));
+        }
+        d.arbiter = Some(arbiter);
+        Ok(())
+    })
+}
+
+pub fn hear(db: &Database, id: u64, new: NewHearing) -> Result<Dispute, KernelError> {
+    let mut problems = Vec::new();
+    require_text(&mut problems, "summary", &new.summary);
+    if !problems.is_empty() {
+        return Err(KernelError::Validation(problems));
+    }
+    update(db, id, |d| {
+        let Some(arbiter) = d.arbiter.clone() else {
+            return Err(KernelError::Conflict(format!("Dispute {} has no arbiter yet", d.id)));
+        };
+        // Further hearings may follow while the dispute is already being heard
+        if d.status != Status::Hearing {
+            d.transition(Status::Hearing)?;
+        }
+        d.hearings.push(Hearing { arbiter, held_at: Utc::now(), summary: new.summary.trim().to_string() });
+        Ok(())
+    })
+}
+
+pub fn rule(db: &Database, id: u64, new: NewRuling) -> Result<Dispute, KernelError> {
+    let mut problems = Vec::new();
+    require_text(&mut problems, "decision", &new.decision);
+    // Transparency: every ruling carries its technical justification
+    require_text(&mut problems, "justification", &new.justification);
+    if !problems.is_empty() {
+        return Err(KernelError::Validation(problems));
+    }
+    update(db, id, |d| {
+        if !d.is_party(&new.in_favor_of) {
+            return Err(KernelError::Validation(vec![format!(
+                "in_favor_of must be '{}' or '{}'",
+                d.complainant, d.respondent
+            )]));
+        }
+        d.transition(Status::Ruled)?;
+        let on_appeal = !d.appeals.is_empty();
+        d.rulings.push(Ruling {
+            arbiter: d.arbiter.clone().unwrap_or_default(),
+            ruled_at: Utc::now(),
+            in_favor_of: new.in_favor_of,
+            decision: new.decision.trim().to_string(),
+            justification: new.justification.trim().to_string(),
+            on_appeal,
+        });
+        Ok(())
+    })
+}
+
+pub fn appeal(db: &Database, id: u64, new: NewAppeal) -> Result<Dispute, KernelError> {
+    let mut problems = Vec::new();
+    require_text(&mut problems, "grounds", &new.grounds);
+    if !problems.is_empty() {
+        return Err(KernelError::Validation(problems));
+    }
+    update(db, id, |d| {
+        if !d.is_party(&new.filed_by) {
+            return Err(KernelError::Validation(vec![format!(
+                "only '{}' or '{}' may appeal",
+                d.complainant, d.respondent
+            )]));
+        }
+        if d.appeals.len() >= MAX_APPEALS {
+            return Err(KernelError::Conflict(format!("Dispute {} was already appealed; the ruling is final", d.id)));
+        }
+        d.transition(Status::Appealed)?;
+        d.appeals.push(Appeal { filed_by: new.filed_by, filed_at: Utc::now(), grounds: new.grounds.trim().to_string() });
+        Ok(())
+    })
+}
+
+// --- CLI: `culture-kernel disputes` ---
+
+#[derive(Subcommand, Debug)]
+pub enum DisputesCommand {
+    /// File a contract dispute between two registered teams
+    File {
+        /// Team filing the complaint
+        complainant: String,
+        /// Team whose API is said to break the contract
+        respondent: String,
+        /// The contract in question, e.g. "payments-api v2: POST /charges"
+        #[arg(long)]
+        contract: String,
+        #[arg(long)]
+        complaint: String,
+        /// Defaults to the sitting Sarkin
+        #[arg(long)]
+        arbiter: Option<String>,
+    },
+    /// List disputes
+    List {
+        /// open, hearing, ruled or appealed
+        #[arg(long)]
+        status: Option<String>,
+        /// Only disputes involving this team
+        #[arg(long)]
+        team: Option<String>,
+    },
+    /// Show a dispute with its hearings, rulings and history
+    Show { id: u64 },
+    /// Assign the arbiter (defaults to the sitting Sarkin)
+    Assign {
+        id: u64,
+        arbiter: Option<String>,
+    },
+    /// Record a hearing
+    Hear {
+        id: u64,
+        #[arg(long)]
+        summary: String,
+    },
+    /// Record the binding ruling
+    Rule {
+        id: u64,
+        /// The party the ruling favours
+        #[arg(long)]
+        in_favor_of: String,
+        #[arg(long)]
+        decision: String,
+        /// Technical justification, published with the ruling
+        #[arg(long)]
+        justification: String,
+    },
+    /// Appeal the ruling (once per dispute)
+    Appeal {
+        id: u64,
+        #[arg(long = "by")]
+        filed_by: String,
+        #[arg(long)]
+        grounds: String,
+    },
+}
+
+fn print_summary(d: &Dispute) {
+    println!(
+        "#{:<4} {:<9} {} vs {} over {}{}",
+        d.id,
+        d.status.as_str(),
+        d.complainant.cyan(),
+        d.respondent.cyan(),
+        d.contract.yellow(),
+        d.arbiter.as_deref().map(|a| format!(" (arbiter: {})", a)).unwrap_or_default()
+    );
+}
+
+fn print_dispute(d: &Dispute) {
+    print_summary(d);
+    println!("      complaint: {}", d.complaint.italic());
+    for h in &d.hearings {
+        println!("      hearing {} ({}): {}", h.held_at.format("%Y-%m-%d"), h.arbiter, h.summary);
+    }
+    for r in &d.rulings {
+        let label = if r.on_appeal { "ruling on appeal" } else { "ruling" };
+        println!(
+            "      {} {} ({}): in favour of {}",
+            label,
+            r.ruled_at.format("%Y-%m-%d"),
+            r.arbiter,
+            r.in_favor_of.green().bold()
+        );
+        println!("        {}", r.decision);
+        println!("        justification: {}", r.justification.italic());
+    }
+    for a in &d.appeals {
+        println!("      appealed {} by {}: {}", a.filed_at.format("%Y-%m-%d"), a.filed_by, a.grounds);
+    }
+}
+
+pub fn disputes_cli(db: &Arc<Database>, command: &DisputesCommand) -> anyhow::Result<()> {
+    match command {
+        DisputesCommand::File { complainant, respondent, contract, complaint, arbiter } => {
+            let d = file(
+                db,
+                NewDispute {
+                    complainant: complainant.clone(),
+                    respondent: respondent.clone(),
+                    contract: contract.clone(),
+                    complaint: complaint.clone(),
+                    arbiter: arbiter.clone(),
+                },
+            )?;
+            println!("{} dispute #{}", "Filed".green().bold(), d.id);
+            if d.arbiter.is_none() {
+                println!("No Sarkin is in office; assign an arbiter with 'culture-kernel disputes assign {} <name>'.", d.id);
+            }
+        }
+        DisputesCommand::List { status, team } => {
+            let disputes = list(db, &DisputeFilter { status: status.clone(), team: team.clone() })?;
+            if disputes.is_empty() {
+                println!("No disputes filed.");
+            }
+            for d in &disputes {
+                print_summary(d);
+            }
+        }
+        DisputesCommand::Show { id } => print_dispute(&get(db, *id)?),
+        DisputesCommand::Assign { id, arbiter } => {
+            let d = assign(db, *id, AssignArbiter { arbiter: arbiter.clone() })?;
+            println!("{} {} to dispute #{}", "Assigned".green().bold(), d.arbiter.unwrap_or_default(), d.id);
+        }
+        DisputesCommand::Hear { id, summary } => {
+            let d = hear(db, *id, NewHearing { summary: summary.clone() })?;
+            println!("{} hearing {} of dispute #{}", "Recorded".green().bold(), d.hearings.len(), d.id);
+        }
+        DisputesCommand::Rule { id, in_favor_of, decision, justification } => {
+            let d = rule(
+                db,
+                *id,
+                NewRuling {
+                    in_favor_of: in_favor_of.clone(),
+                    decision: decision.clone(),
+                    justification: justification.clone(),
+                },
+            )?;
+            println!("{} dispute #{} in favour of {}", "Ruled".green().bold(), d.id, in_favor_of.cyan());
+        }
+        DisputesCommand::Appeal { id, filed_by, grounds } => {
+            let d = appeal(db, *id, NewAppeal { filed_by: filed_by.clone(), grounds: grounds.clone() })?;
+            println!("{} dispute #{}; assign an arbiter or schedule a new hearing", "Appealed".yellow().bold(), d.id);
+        }
+    }
+    Ok(())
+}
+
+// --- API: /guild/disputes ---
+
+// POST /guild/disputes
+pub async fn api_file(
+    State(db): State<Arc<Database>>,
+    body: Result<Json<NewDispute>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(new) = body?;
+    let dispute = file(&db, new)?;
+    Ok((StatusCode::CREATED, Json(dispute)).into_response())
+}
+
+// GET /guild/disputes?status=&team=
+pub async fn api_list(
+    State(db): State<Arc<Database>>,
+    Query(filter): Query<DisputeFilter>,
+) -> Result<Response, KernelError> {
+    Ok(Json(list(&db, &filter)?).into_response())
+}
+
+// GET /guild/disputes/{id}
+pub async fn api_get(State(db): State<Arc<Database>>, Path(id): Path<u64>) -> Result<Response, KernelError> {
+    Ok(Json(get(&db, id)?).into_response())
+}
+
+// PUT /guild/disputes/{id}/arbiter
+pub async fn api_assign(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<u64>,
+    body: Result<Json<AssignArbiter>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(assignment) = body?;
+    Ok(Json(assign(&db, id, assignment)?).into_response())
+}
+
+// POST /guild/disputes/{id}/hearings
+pub async fn api_hear(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<u64>,
+    body: Result<Json<NewHearing>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(new) = body?;
+    let dispute = hear(&db, id, new)?;
+    Ok((StatusCode::CREATED, Json(dispute)).into_response())
+}
+
+// POST /guild/disputes/{id}/rulings
+pub async fn api_rule(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<u64>,
+    body: Result<Json<NewRuling>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(new) = body?;
+    let dispute = rule(&db, id, new)?;
+    Ok((StatusCode::CREATED, Json(dispute)).into_response())
+}
+
+// POST /guild/disputes/{id}/appeals
+pub async fn api_appeal(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<u64>,
+    body: Result<Json<NewAppeal>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(new) = body?;
+    let dispute = appeal(&db, id, new)?;
+    Ok((StatusCode::CREATED, Json(dispute)).into_response())
+}
@@ -0,0 +1,204 @@
+// --- DOMAIN ERRORS ---
+// Every failure the kernel can report, rendered as RFC 7807 problem+json
+// (or a short plain-text line for terminal clients).
+
+use axum::{
+    extract::rejection::JsonRejection,
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+};
+use colored::*;
+use serde::Serialize;
+
+use crate::render::OutputFormat;
+
+#[derive(Debug, thiserror::Error)]
+pub enum KernelError {
+    #[error("storage failure: {0}")]
+    Storage(#[from] redb::Error),
+
+    #[error("no {kind} with id '{id}'")]
+    NotFound { kind: &'static str, id: String },
+
+    #[error("{0}")]
+    Conflict(String),
+
+    #[error("{0}")]
+    Forbidden(String),
+
+    #[error("validation failed: {}", .0.join("; "))]
+    Validation(Vec<String>),
+
+    #[error("internal failure: {0}")]
+    Internal(String),
+
+    #[error("serialization failure: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    #[error("invalid request body: {0}")]
+    InvalidBody(#[from] JsonRejection),
+
+    #[error("unknown format '{0}'; use json, text, ansi, html or markdown")]
+    UnsupportedFormat(String),
+
+    #[error("none of the requested media types can be produced; supported: application/json, text/plain, text/x-ansi, text/html, text/markdown")]
+    NotAcceptable,
+}
+
+// redb splits its errors per operation; they all funnel into redb::Error
+macro_rules! storage_from {
+    ($($t:ty),*) => {
+        $(impl From<$t> for KernelError {
+            fn from(e: $t) -> Self {
+                KernelError::Storage(e.into())
+            }
+        })*
+    };
+}
+
+storage_from!(
+    redb::DatabaseError,
+    redb::TransactionError,
+    redb::TableError,
+    redb::StorageError,
+    redb::CommitError
+);
+
+impl KernelError {
+    pub fn not_found(kind: &'static str, id: impl Into<String>) -> Self {
+        KernelError::NotFound { kind, id: id.into() }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        match self {
+            KernelError::Storage(_) | KernelError::Serialization(_) | KernelError::Internal(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+            KernelError::NotFound { .. } => StatusCode::NOT_FOUND,
+            KernelError::Conflict(_) => StatusCode::CONFLICT,
+            KernelError::Forbidden(_) => StatusCode::FORBIDDEN,
+            KernelError::Validation(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            KernelError::InvalidBody(rejection) => rejection.status(),
+            KernelError::UnsupportedFormat(_) => StatusCode::BAD_REQUEST,
+            KernelError::NotAcceptable => StatusCode::NOT_ACCEPTABLE,
+        }
+    }
+
+    // Stable machine-readable slug, used in the problem "type" URI
+    fn slug(&self) -> &'static str {
+        match self {
+            KernelError::Storage(_) => "storage",
+            KernelError::NotFound { .. } => "not-found",
+            KernelError::Conflict(_) => "conflict",
+            KernelError::Forbidden(_) => "forbidden",
+            KernelError::Validation(_) => "validation",
+            KernelError::Serialization(_) => "serialization",
+            KernelError::Internal(_) => "internal",
+            KernelError::InvalidBody(_) => "invalid-body",
+            KernelError::UnsupportedFormat(_) => "unsupported-format",
+            KernelError::NotAcceptable => "not-acceptable",
+        }
+    }
+
+    // Internal failures are logged in full but never leak redb/serde details to clients
+    fn detail(&self) -> String {
+        match self {
+            KernelError::Storage(_) => "The ritual store could not complete the request.".to_string(),
+            KernelError::Serialization(_) => "A stored record could not be encoded or decoded.".to_string(),
+            KernelError::Internal(_) => "The kernel could not complete the request.".to_string(),
+            // body_text() carries serde's field-level message ("missing field `name`")
+            KernelError::InvalidBody(rejection) => rejection.body_text(),
+            other => {
+                let mut detail = other.to_string();
+                if let Some(first) = detail.get_mut(0..1) {
+                    first.make_ascii_uppercase();
+                }
+                detail
+            }
+        }
+    }
+
+    fn problem(&self) -> Problem {
+        let status = self.status();
+        let mut extensions = serde_json::Map::new();
+        match self {
+            KernelError::NotFound { kind, id } => {
+                extensions.insert("kind".into(), (*kind).into());
+                extensions.insert("id".into(), id.clone().into());
+            }
+            KernelError::Validation(problems) => {
+                extensions.insert("problems".into(), problems.clone().into());
+            }
+            _ => {}
+        }
+
+        Problem {
+            problem_type: format!("urn:culture-kernel:problem:{}", self.slug()),
+            title: status.canonical_reason().unwrap_or("Error").to_string(),
+            status: status.as_u16(),
+            detail: self.detail(),
+            extensions,
+        }
+    }
+
+    // Terminal clients get a readable line instead of a JSON document
+    pub fn respond(self, format: OutputFormat) -> Response {
+        match format {
+            OutputFormat::Plain | OutputFormat::Ansi => self.text_response(format == OutputFormat::Ansi),
+            _ => self.into_response(),
+        }
+    }
+
+    fn text_response(self, coloured: bool) -> Response {
+        self.log();
+        let problem = self.problem();
+
+        let headline = format!("{} {}", problem.status, problem.title);
+        let mut body = if coloured {
+            format!("{} {}\n", "✖".red().bold(), headline.red().bold())
+        } else {
+            format!("ERROR {}\n", headline)
+        };
+        body.push_str(&format!("  {}\n", problem.detail));
+        if let KernelError::Validation(problems) = &self {
+            for p in problems {
+                body.push_str(&format!("  - {}\n", p));
+            }
+        }
+
+        (self.status(), body).into_response()
+    }
+
+    fn log(&self) {
+        if self.status().is_server_error() {
+            eprintln!("{} {}", "KERNEL ERROR".red().bold(), self);
+        }
+    }
+}
+
+impl IntoResponse for KernelError {
+    fn into_response(self) -> Response {
+        self.log();
+        let status = self.status();
+        let body = serde_json::to_vec(&self.problem()).unwrap_or_default();
+
+        let mut response = (status, body).into_response();
+        response.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/problem+json"),
+        );
+        response
+    }
+}
+
+// RFC 7807 problem details document
+#[derive(Debug, Serialize)]
+struct Problem {
+    #[serde(rename = "type")]
+    problem_type: String,
+    title: String,
+    status: u16,
+    detail: String,
+    #[serde(flatten)]
+    extensions: serde_json::Map<String, serde_json::Value>,
+}
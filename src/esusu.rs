@@ -0,0 +1,786 @@
+// --- ESUSU ROTATING POOLS ---
+// Executable form of YORUBA_05_ESUSU. A pool has a fixed list of member teams and a
+// per-round contribution. Every member pays in each round (CONTRIBUTIONS_TABLE); once
+// all have paid, the round closes and the whole pot goes to one recipient (ROUNDS_TABLE).
+// Nobody receives twice in a cycle, so each cycle has one round per member. The
+// recipient is picked by the pool's selection rule:
+//   fixed   - the next member in the pool's order
+//   vote    - the member with the most votes (need-based), ties go to the earlier member
+//   lottery - sha256("{seed}:{round}"); its first 8 bytes, big-endian, modulo the number
+//             of eligible members (in pool order) is the winner's index. The seed and the
+//             digest are stored with the round so anyone can re-run the draw.
+
+use axum::{
+    extract::{rejection::JsonRejection, Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use clap::{Subcommand, ValueEnum};
+use colored::*;
+use redb::{Database, ReadableTable, TableDefinition};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::error::KernelError;
+use crate::teams;
+
+// pool id -> Pool JSON
+const POOLS_TABLE: TableDefinition<&str, &str> = TableDefinition::new("esusu_pools");
+// (pool id, round, member) -> Contribution JSON
+const CONTRIBUTIONS_TABLE: TableDefinition<(&str, u64, &str), &str> = TableDefinition::new("esusu_contributions");
+// (pool id, round) -> Round JSON, written when the round closes
+const ROUNDS_TABLE: TableDefinition<(&str, u64), &str> = TableDefinition::new("esusu_rounds");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Selection {
+    /// Members receive in the order they were listed
+    Fixed,
+    /// Members vote for the most pressing need each round
+    Vote,
+    /// A seeded draw anyone can verify
+    Lottery,
+}
+
+impl Selection {
+    fn as_str(self) -> &'static str {
+        match self {
+            Selection::Fixed => "fixed",
+            Selection::Vote => "vote",
+            Selection::Lottery => "lottery",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Pool {
+    id: String,
+    name: String,
+    // Team ids, in rotation order
+    members: Vec<String>,
+    contribution: f64,
+    selection: Selection,
+    // Lottery pools only; published so every draw can be checked
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    seed: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Contribution {
+    pool: String,
+    round: u64,
+    member: String,
+    amount: f64,
+    recorded_at: DateTime<Utc>,
+}
+
+// How a lottery winner was drawn
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Draw {
+    seed: String,
+    digest: String,
+    eligible: Vec<String>,
+    index: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Round {
+    round: u64,
+    cycle: u64,
+    recipient: String,
+    payout: f64,
+    selection: Selection,
+    // Vote pools: voter -> candidate, and the resulting tally
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    votes: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    tally: BTreeMap<String, usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    draw: Option<Draw>,
+    // What the money is ring-fenced for
+    #[serde(default)]
+    purpose: String,
+    closed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Balance {
+    member: String,
+    contributed: f64,
+    received: f64,
+    net: f64,
+}
+
+// A pool with everything recorded against it
+struct PoolState {
+    pool: Pool,
+    contributions: Vec<Contribution>,
+    rounds: Vec<Round>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PoolStatus {
+    #[serde(flatten)]
+    pool: Pool,
+    current_round: u64,
+    cycle: u64,
+    // Paid into the current round so far
+    collected: f64,
+    // Members who still owe the current round
+    outstanding: Vec<String>,
+    // Members who can still receive in this cycle
+    eligible: Vec<String>,
+    balances: Vec<Balance>,
+}
+
+impl PoolState {
+    fn current_round(&self) -> u64 {
+        self.rounds.last().map_or(1, |r| r.round + 1)
+    }
+
+    fn cycle_of(&self, round: u64) -> u64 {
+        (round - 1) / self.pool.members.len() as u64 + 1
+    }
+
+    // Members who have not received yet in the current round's cycle, in pool order
+    fn eligible(&self) -> Vec<String> {
+        let cycle = self.cycle_of(self.current_round());
+        self.pool
+            .members
+            .iter()
+            .filter(|m| !self.rounds.iter().any(|r| r.cycle == cycle && &r.recipient == *m))
+            .cloned()
+            .collect()
+    }
+
+    fn paid(&self, round: u64) -> impl Iterator<Item = &Contribution> {
+        self.contributions.iter().filter(move |c| c.round == round)
+    }
+
+    fn outstanding(&self) -> Vec<String> {
+        let round = self.current_round();
+        self.pool
+            .members
+            .iter()
+            .filter(|m| !self.paid(round).any(|c| &c.member == *m))
+            .cloned()
+            .collect()
+    }
+
+    fn balances(&self) -> Vec<Balance> {
+        self.pool
+            .members
+            .iter()
+            .map(|m| {
+                let contributed = self.contributions.iter().filter(|c| &c.member == m).fold(0.0, |sum, c| sum + c.amount);
+                let received = self.rounds.iter().filter(|r| &r.recipient == m).fold(0.0, |sum, r| sum + r.payout);
+                Balance { member: m.clone(), contributed, received, net: received - contributed }
+            })
+            .collect()
+    }
+
+    fn status(self) -> PoolStatus {
+        let current_round = self.current_round();
+        PoolStatus {
+            current_round,
+            cycle: self.cycle_of(current_round),
+            collected: self.paid(current_round).fold(0.0, |sum, c| sum + c.amount),
+            outstanding: self.outstanding(),
+            eligible: self.eligible(),
+            balances: self.balances(),
+            pool: self.pool,
+        }
+    }
+}
+
+// Pool ids appear in URLs: lowercase letters, digits and dashes
+fn validate_pool_id(id: &str) -> Result<(), String> {
+    let valid = !id.is_empty() && id.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("pool id '{}' must be non-empty and use only a-z, 0-9 and '-'", id))
+    }
+}
+
+fn read_state(
+    pools: &impl ReadableTable<&'static str, &'static str>,
+    contributions: &impl ReadableTable<(&'static str, u64, &'static str), &'static str>,
+    rounds: &impl ReadableTable<(&'static str, u64), &'static str>,
+    id: &str,
+) -> Result<Option<PoolState>, KernelError> {
+    let pool: Pool = match pools.get(id)? {
+        Some(v) => serde_json::from_str(v.value())?,
+        None => return Ok(None),
+    };
+
+    let mut state = PoolState { pool, contributions: Vec::new(), rounds: Vec::new() };
+    for item in contributions.range((id, 0, "")..)? {
+        let (key, value) = item?;
+        if key.value().0 != id {
+            break;
+        }
+        state.contributions.push(serde_json::from_str(value.value())?);
+    }
+    for item in rounds.range((id, 0)..)? {
+        let (key, value) = item?;
+        if key.value().0 != id {
+            break;
+        }
+        state.rounds.push(serde_json::from_str(value.value())?);
+    }
+    Ok(Some(state))
+}
+
+fn load_state(db: &Database, id: &str) -> Result<PoolState, KernelError> {
+    let read_txn = db.begin_read()?;
+    let pools = match read_txn.open_table(POOLS_TABLE) {
+        Ok(t) => t,
+        Err(redb::TableError::TableDoesNotExist(_)) => return Err(KernelError::not_found("pool", id)),
+        Err(e) => return Err(e.into()),
+    };
+    // create_pool creates all three tables together
+    let contributions = read_txn.open_table(CONTRIBUTIONS_TABLE)?;
+    let rounds = read_txn.open_table(ROUNDS_TABLE)?;
+    read_state(&pools, &contributions, &rounds, id)?.ok_or_else(|| KernelError::not_found("pool", id))
+}
+
+pub fn list_pools(db: &Database) -> Result<Vec<Pool>, KernelError> {
+    let read_txn = db.begin_read()?;
+    let table = match read_txn.open_table(POOLS_TABLE) {
+        Ok(t) => t,
+        Err(redb::TableError::TableDoesNotExist(_)) => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut pools = Vec::new();
+    for item in table.iter()? {
+        let (_, value) = item?;
+        pools.push(serde_json::from_str(value.value())?);
+    }
+    Ok(pools)
+}
+
+pub fn status(db: &Database, id: &str) -> Result<PoolStatus, KernelError> {
+    Ok(load_state(db, id)?.status())
+}
+
+pub fn history(db: &Database, id: &str) -> Result<Vec<Round>, KernelError> {
+    Ok(load_state(db, id)?.rounds)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NewPool {
+    id: String,
+    name: Option<String>,
+    members: Vec<String>,
+    contribution: f64,
+    selection: Selection,
+    // Lottery only; derived from the pool id and creation time when omitted
+    seed: Option<String>,
+}
+
+pub fn create_pool(db: &Database, new: NewPool) -> Result<Pool, KernelError> {
+    let mut problems = Vec::new();
+    if let Err(problem) = validate_pool_id(&new.id) {
+        problems.push(problem);
+    }
+    let members: Vec<String> = new.members.iter().map(|m| m.trim().to_string()).collect();
+    if members.len() < 2 {
+        problems.push("a pool needs at least 2 members".to_string());
+    }
+    for (i, member) in members.iter().enumerate() {
+        if members[..i].contains(member) {
+            problems.push(format!("member '{}' is listed twice", member));
+        }
+    }
+    if !new.contribution.is_finite() || new.contribution <= 0.0 {
+        problems.push("contribution must be greater than 0".to_string());
+    }
+    if new.seed.is_some() && new.selection != Selection::Lottery {
+        problems.push("seed only applies to lottery pools".to_string());
+    }
+    if new.seed.as_deref().is_some_and(|s| s.trim().is_empty()) {
+        problems.push("seed must not be empty".to_string());
+    }
+    if !problems.is_empty() {
+        return Err(KernelError::Validation(problems));
+    }
+    // Members are registered teams, so typos cannot create phantom squads
+    for member in &members {
+        teams::require_team(db, member)?;
+    }
+
+    let created_at = Utc::now();
+    let seed = (new.selection == Selection::Lottery).then(|| {
+        new.seed.unwrap_or_else(|| {
+            let digest = Sha256::digest(format!("{}:{}", new.id, created_at.timestamp_nanos_opt().unwrap_or_default()));
+            format!("{:x}", digest)[..16].to_string()
+        })
+    });
+    let pool = Pool {
+        name: new.name.filter(|n| !n.trim().is_empty()).unwrap_or_else(|| new.id.clone()),
+        id: new.id,
+        members,
+        contribution: new.contribution,
+        selection: new.selection,
+        seed,
+        created_at,
+    };
+
+    let write_txn = db.begin_write()?;
+    {
+        let mut pools = write_txn.open_table(POOLS_TABLE)?;
+        if pools.get(pool.id.as_str())?.is_some() {
+            return Err(KernelError::Conflict(format!("A pool with id '{}' already exists", pool.id)));
+        }
+        let json = serde_json::to_string(&pool)?;
+        pools.insert(pool.id.as_str(), json.as_str())?;
+        write_txn.open_table(CONTRIBUTIONS_TABLE)?;
+        write_txn.open_table(ROUNDS_TABLE)?;
+    }
+    write_txn.commit()?;
+    Ok(pool)
+}
+
+// Removes a pool with its contributions and round history
+pub fn delete_pool(db: &Database, id: &str) -> Result<Option<Pool>, KernelError> {
+    let write_txn = db.begin_write()?;
+    let removed = {
+        let mut pools = write_txn.open_table(POOLS_TABLE)?;
+        let removed: Option<Pool> = match pools.remove(id)? {
+            Some(v) => Some(serde_json::from_str(v.value())?),
+            None => None,
+        };
+
+        let mut contributions = write_txn.open_table(CONTRIBUTIONS_TABLE)?;
+        let mut keys = Vec::new();
+        for item in contributions.range((id, 0, "")..)? {
+            let (key, _) = item?;
+            let (pool, round, member) = key.value();
+            if pool != id {
+                break;
+            }
+            keys.push((round, member.to_string()));
+        }
+        for (round, member) in &keys {
+            contributions.remove((id, *round, member.as_str()))?;
+        }
+
+        let mut rounds = write_txn.open_table(ROUNDS_TABLE)?;
+        let mut numbers = Vec::new();
+        for item in rounds.range((id, 0)..)? {
+            let (key, _) = item?;
+            let (pool, round) = key.value();
+            if pool != id {
+                break;
+            }
+            numbers.push(round);
+        }
+        for round in numbers {
+            rounds.remove((id, round))?;
+        }
+        removed
+    };
+    write_txn.commit()?;
+    Ok(removed)
+}
+
+// Records `member`'s payment into the current round
+pub fn contribute(db: &Database, id: &str, member: &str) -> Result<Contribution, KernelError> {
+    let write_txn = db.begin_write()?;
+    let contribution = {
+        let pools = write_txn.open_table(POOLS_TABLE)?;
+        let mut contributions = write_txn.open_table(CONTRIBUTIONS_TABLE)?;
+        let rounds = write_txn.open_table(ROUNDS_TABLE)?;
+        let state = read_state(&pools, &contributions, &rounds, id)?.ok_or_else(|| KernelError::not_found("pool", id))?;
+
+        if !state.pool.members.iter().any(|m| m == member) {
+            return Err(KernelError::not_found("member", format!("{}/{}", id, member)));
+        }
+        let round = state.current_round();
+        if state.paid(round).any(|c| c.member == member) {
+            return Err(KernelError::Conflict(format!(
+                "'{}' has already contributed to round {} of pool '{}'",
+                member, round, id
+            )));
+        }
+
+        let contribution = Contribution {
+            pool: id.to_string(),
+            round,
+            member: member.to_string(),
+            amount: state.pool.contribution,
+            recorded_at: Utc::now(),
+        };
+        let json = serde_json::to_string(&contribution)?;
+        contributions.insert((id, round, member), json.as_str())?;
+        contribution
+    };
+    write_txn.commit()?;
+    Ok(contribution)
+}
+
+fn lottery(seed: &str, round: u64, eligible: Vec<String>) -> Draw {
+    let digest = Sha256::digest(format!("{}:{}", seed, round));
+    let mut head = [0u8; 8];
+    head.copy_from_slice(&digest[..8]);
+    let index = (u64::from_be_bytes(head) % eligible.len() as u64) as usize;
+    Draw { seed: seed.to_string(), digest: format!("{:x}", digest), eligible, index }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct CloseRound {
+    // Vote pools: voter -> candidate
+    #[serde(default)]
+    votes: BTreeMap<String, String>,
+    #[serde(default)]
+    purpose: String,
+}
+
+// Closes the current round once every member has paid and hands the pot to the selected recipient
+pub fn close_round(db: &Database, id: &str, close: CloseRound) -> Result<Round, KernelError> {
+    let write_txn = db.begin_write()?;
+    let round = {
+        let pools = write_txn.open_table(POOLS_TABLE)?;
+        let contributions = write_txn.open_table(CONTRIBUTIONS_TABLE)?;
+        let mut rounds = write_txn.open_table(ROUNDS_TABLE)?;
+        let state = read_state(&pools, &contributions, &rounds, id)?.ok_or_else(|| KernelError::not_found("pool", id))?;
+
+        let number = state.current_round();
+        let outstanding = state.outstanding();
+        if !outstanding.is_empty() {
+            return Err(KernelError::Conflict(format!(
+                "Round {} of pool '{}' is still waiting for contributions from: {}",
+                number,
+                id,
+                outstanding.join(", ")
+            )));
+        }
+        let selection = state.pool.selection;
+        if selection != Selection::Vote && !close.votes.is_empty() {
+            return Err(KernelError::Validation(vec!["votes only apply to vote pools".to_string()]));
+        }
+
+        let eligible = state.eligible();
+        let mut tally = BTreeMap::new();
+        let mut draw = None;
+        let recipient = match selection {
+            Selection::Fixed => eligible[0].clone(),
+            Selection::Vote => {
+                let mut problems = Vec::new();
+                if close.votes.is_empty() {
+                    problems.push("vote pools need at least one vote to close a round".to_string());
+                }
+                for (voter, candidate) in &close.votes {
+                    if !state.pool.members.contains(voter) {
+                        problems.push(format!("'{}' is not a member of pool '{}'", voter, id));
+                    }
+                    if !eligible.contains(candidate) {
+                        problems.push(format!(
+                            "'{}' cannot receive in this cycle (eligible: {})",
+                            candidate,
+                            eligible.join(", ")
+                        ));
+                    }
+                }
+                if !problems.is_empty() {
+                    return Err(KernelError::Validation(problems));
+                }
+                for candidate in close.votes.values() {
+                    *tally.entry(candidate.clone()).or_insert(0) += 1;
+                }
+                // Most votes wins; `eligible` is in pool order, so ties go to the earlier member
+                let top = tally.values().copied().max().unwrap_or(0);
+                eligible.iter().find(|m| tally.get(*m) == Some(&top)).cloned().unwrap_or_default()
+            }
+            Selection::Lottery => {
+                let seed = state.pool.seed.clone().unwrap_or_default();
+                let d = lottery(&seed, number, eligible);
+                let recipient = d.eligible[d.index].clone();
+                draw = Some(d);
+                recipient
+            }
+        };
+
+        let round = Round {
+            round: number,
+            cycle: state.cycle_of(number),
+            recipient,
+            payout: state.paid(number).fold(0.0, |sum, c| sum + c.amount),
+            selection,
+            votes: close.votes,
+            tally,
+            draw,
+            purpose: close.purpose.trim().to_string(),
+            closed_at: Utc::now(),
+        };
+        let json = serde_json::to_string(&round)?;
+        rounds.insert((id, number), json.as_str())?;
+        round
+    };
+    write_txn.commit()?;
+    Ok(round)
+}
+
+// --- CLI: `culture-kernel esusu` ---
+
+#[derive(Subcommand, Debug)]
+pub enum EsusuCommand {
+    /// List pools
+    List,
+    /// Start a pool between registered teams
+    Create {
+        /// Pool id, e.g. q3-innovation
+        id: String,
+        /// Member team ids in rotation order (repeat the flag or separate with commas)
+        #[arg(short, long = "member", value_delimiter = ',', required = true)]
+        members: Vec<String>,
+        /// Amount every member pays into each round
+        #[arg(long)]
+        contribution: f64,
+        #[arg(long, value_enum, default_value_t = Selection::Fixed)]
+        selection: Selection,
+        /// Lottery seed (published with every draw)
+        #[arg(long)]
+        seed: Option<String>,
+        #[arg(long)]
+        name: Option<String>,
+    },
+    /// Show the current round, who still owes it and every member's balance
+    Show { id: String },
+    /// Record a member's payment into the current round
+    Contribute { id: String, member: String },
+    /// Close the current round and pay out the pot
+    Close {
+        id: String,
+        /// Vote pools: VOTER=CANDIDATE (repeatable)
+        #[arg(long = "vote")]
+        votes: Vec<String>,
+        /// What the payout is ring-fenced for
+        #[arg(long, default_value = "")]
+        purpose: String,
+    },
+    /// Every closed round of a pool
+    Rounds { id: String },
+    /// Delete a pool and its history
+    Remove { id: String },
+}
+
+fn print_round(r: &Round) {
+    println!(
+        "  #{:<3} cycle {} {} received {:.2} ({})",
+        r.round,
+        r.cycle,
+        r.recipient.cyan().bold(),
+        r.payout,
+        r.selection.as_str()
+    );
+    if !r.tally.is_empty() {
+        let tally: Vec<String> = r.tally.iter().map(|(m, n)| format!("{} {}", m, n)).collect();
+        println!("       votes: {}", tally.join(", "));
+    }
+    if let Some(d) = &r.draw {
+        println!(
+            "       draw: sha256(\"{}:{}\") = {}… → index {} of [{}]",
+            d.seed,
+            r.round,
+            &d.digest[..16],
+            d.index,
+            d.eligible.join(", ")
+        );
+    }
+    if !r.purpose.is_empty() {
+        println!("       purpose: {}", r.purpose.italic());
+    }
+}
+
+pub fn esusu_cli(db: &Arc<Database>, command: &EsusuCommand) -> anyhow::Result<()> {
+    match command {
+        EsusuCommand::List => {
+            let pools = list_pools(db)?;
+            if pools.is_empty() {
+                println!("No pools yet. Start one with 'culture-kernel esusu create <id> -m team-a,team-b --contribution 100'.");
+            }
+            for pool in pools {
+                println!(
+                    "{} {} ({}, {} members, {:.2} per round)",
+                    pool.id.cyan().bold(),
+                    pool.name,
+                    pool.selection.as_str(),
+                    pool.members.len(),
+                    pool.contribution
+                );
+            }
+        }
+        EsusuCommand::Create { id, members, contribution, selection, seed, name } => {
+            let pool = create_pool(
+                db,
+                NewPool {
+                    id: id.clone(),
+                    name: name.clone(),
+                    members: members.clone(),
+                    contribution: *contribution,
+                    selection: *selection,
+                    seed: seed.clone(),
+                },
+            )?;
+            println!("{} pool {} ({})", "Created".green().bold(), pool.id.cyan(), pool.members.join(" → "));
+            if let Some(seed) = &pool.seed {
+                println!("  lottery seed: {}", seed);
+            }
+        }
+        EsusuCommand::Show { id } => {
+            let s = status(db, id)?;
+            println!("{}", format!(" {} ({}) ", s.pool.name.to_uppercase(), s.pool.id).on_blue().white().bold());
+            println!(
+                "  Round {} (cycle {}): {:.2} collected, {} selection",
+                s.current_round,
+                s.cycle,
+                s.collected,
+                s.pool.selection.as_str()
+            );
+            if s.outstanding.is_empty() {
+                println!("  {} every member has paid; close the round to pay out", "READY".green().bold());
+            } else {
+                println!("  Waiting for: {}", s.outstanding.join(", ").yellow());
+            }
+            println!("  Can still receive this cycle: {}", s.eligible.join(", "));
+            println!("\n  {:<20} {:>12} {:>12} {:>12}", "MEMBER", "CONTRIBUTED", "RECEIVED", "NET");
+            for b in &s.balances {
+                println!("  {:<20} {:>12.2} {:>12.2} {:>12.2}", b.member, b.contributed, b.received, b.net);
+            }
+        }
+        EsusuCommand::Contribute { id, member } => {
+            let c = contribute(db, id, member)?;
+            println!(
+                "{} {:.2} from {} into round {} of {}",
+                "Recorded".green().bold(),
+                c.amount,
+                c.member.cyan(),
+                c.round,
+                id
+            );
+        }
+        EsusuCommand::Close { id, votes, purpose } => {
+            let mut parsed = BTreeMap::new();
+            for vote in votes {
+                let (voter, candidate) = vote
+                    .split_once('=')
+                    .ok_or_else(|| anyhow::anyhow!("vote '{}' must look like VOTER=CANDIDATE", vote))?;
+                parsed.insert(voter.trim().to_string(), candidate.trim().to_string());
+            }
+            let round = close_round(db, id, CloseRound { votes: parsed, purpose: purpose.clone() })?;
+            println!("{} round {} of {}", "Closed".green().bold(), round.round, id);
+            print_round(&round);
+        }
+        EsusuCommand::Rounds { id } => {
+            let rounds = history(db, id)?;
+            if rounds.is_empty() {
+                println!("No rounds closed yet.");
+            }
+            for round in &rounds {
+                print_round(round);
+            }
+        }
+        EsusuCommand::Remove { id } => {
+            delete_pool(db, id)?.ok_or_else(|| KernelError::not_found("pool", id))?;
+            println!("{} pool {}", "Removed".red().bold(), id.cyan());
+        }
+    }
+    Ok(())
+}
+
+// --- API: /esusu ---
+
+#[derive(Debug, Deserialize)]
+pub struct NewContribution {
+    member: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RoundFilter {
+    round: Option<u64>,
+}
+
+// GET /esusu
+pub async fn api_list(State(db): State<Arc<Database>>) -> Result<Response, KernelError> {
+    Ok(Json(list_pools(&db)?).into_response())
+}
+
+// POST /esusu
+pub async fn api_create(
+    State(db): State<Arc<Database>>,
+    body: Result<Json<NewPool>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(new) = body?;
+    let pool = create_pool(&db, new)?;
+    Ok((StatusCode::CREATED, Json(pool)).into_response())
+}
+
+// GET /esusu/{pool}
+pub async fn api_status(State(db): State<Arc<Database>>, Path(id): Path<String>) -> Result<Response, KernelError> {
+    Ok(Json(status(&db, &id)?).into_response())
+}
+
+// DELETE /esusu/{pool}
+pub async fn api_delete(State(db): State<Arc<Database>>, Path(id): Path<String>) -> Result<Response, KernelError> {
+    let removed = delete_pool(&db, &id)?.ok_or_else(|| KernelError::not_found("pool", &id))?;
+    Ok(Json(removed).into_response())
+}
+
+// GET /esusu/{pool}/balances
+pub async fn api_balances(State(db): State<Arc<Database>>, Path(id): Path<String>) -> Result<Response, KernelError> {
+    Ok(Json(load_state(&db, &id)?.balances()).into_response())
+}
+
+// GET /esusu/{pool}/contributions?round=
+pub async fn api_contributions(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<String>,
+    Query(filter): Query<RoundFilter>,
+) -> Result<Response, KernelError> {
+    let contributions: Vec<Contribution> = load_state(&db, &id)?
+        .contributions
+        .into_iter()
+        .filter(|c| filter.round.is_none_or(|r| c.round == r))
+        .collect();
+    Ok(Json(contributions).into_response())
+}
+
+// POST /esusu/{pool}/contributions
+pub async fn api_contribute(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<String>,
+    body: Result<Json<NewContribution>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(new) = body?;
+    let contribution = contribute(&db, &id, &new.member)?;
+    Ok((StatusCode::CREATED, Json(contribution)).into_response())
+}
+
+// GET /esusu/{pool}/rounds
+pub async fn api_rounds(State(db): State<Arc<Database>>, Path(id): Path<String>) -> Result<Response, KernelError> {
+    Ok(Json(history(&db, &id)?).into_response())
+}
+
+// POST /esusu/{pool}/rounds
+// The body is optional: fixed and lottery pools close with an empty POST, vote pools send `votes`
+pub async fn api_close_round(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<String>,
+    body: Result<Json<CloseRound>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let close = match body {
+        Ok(Json(close)) => close,
+        Err(JsonRejection::MissingJsonContentType(_)) => CloseRound::default(),
+        Err(e) => return Err(e.into()),
+    };
+    let round = close_round(&db, &id, close)?;
+    Ok((StatusCode::CREATED, Json(round)).into_response())
+}
@@ -0,0 +1,919 @@
+// --- SARKIN KASUWA GUILD ELECTIONS ---
+// Executable form of HAUSA_09_SARKIN_KASUWA. Engineers elect the Sarkin Code for a
+// fixed 6-month term. An election moves nominating -> voting -> closed and is tallied
+// by plurality or ranked-choice (instant runoff). Ballots are secret: a voter is only
+// stored as a salted hash (the salt never leaves the kernel), which is enough to stop
+// double voting. Every ballot is chained to the previous one by sha256, so the public
+// ballot log can be recounted and checked for tampering. The sitting Sarkin may not
+// stand for the next term, and a new term only starts when the current one has ended.
+
+use axum::{
+    extract::{rejection::JsonRejection, Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Months, Utc};
+use clap::{Subcommand, ValueEnum};
+use colored::*;
+use redb::{Database, ReadableTable, TableDefinition};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::error::KernelError;
+
+// election id -> Election JSON
+const ELECTIONS_TABLE: TableDefinition<u64, &str> = TableDefinition::new("guild_elections");
+// (election id, ballot seq) -> Ballot JSON
+const BALLOTS_TABLE: TableDefinition<(u64, u64), &str> = TableDefinition::new("guild_ballots");
+// election id -> salt for hashing voter names; never served
+const SALTS_TABLE: TableDefinition<u64, &str> = TableDefinition::new("guild_salts");
+// election id -> Term JSON of the Sarkin it seated
+const TERMS_TABLE: TableDefinition<u64, &str> = TableDefinition::new("guild_terms");
+
+const TERM_MONTHS: u32 = 6;
+// prev_hash of the first ballot in every election
+const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "kebab-case")]
+pub enum Method {
+    /// One choice per ballot; most first choices wins
+    Plurality,
+    /// Ballots rank candidates; the weakest is eliminated until someone has a majority
+    RankedChoice,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Phase {
+    Nominating,
+    Voting,
+    Closed,
+}
+
+impl Method {
+    fn as_str(self) -> &'static str {
+        match self {
+            Method::Plurality => "plurality",
+            Method::RankedChoice => "ranked-choice",
+        }
+    }
+}
+
+impl Phase {
+    fn as_str(self) -> &'static str {
+        match self {
+            Phase::Nominating => "nominating",
+            Phase::Voting => "voting",
+            Phase::Closed => "closed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Nomination {
+    candidate: String,
+    nominated_by: String,
+    nominated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tally {
+    winner: String,
+    ballots: usize,
+    // First-choice counts per round (a single round for plurality)
+    rounds: Vec<BTreeMap<String, usize>>,
+    // Ranked-choice: candidates in the order they were eliminated
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    eliminated: Vec<String>,
+    // Ballots with no remaining choice by the final round
+    exhausted: usize,
+    // Set when the winner was picked between equal counts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tie_break: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Election {
+    id: u64,
+    method: Method,
+    phase: Phase,
+    candidates: Vec<Nomination>,
+    opened_at: DateTime<Utc>,
+    voting_opened_at: Option<DateTime<Utc>>,
+    closed_at: Option<DateTime<Utc>>,
+    result: Option<Tally>,
+}
+
+// One entry of the public ballot log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ballot {
+    seq: u64,
+    // Receipt for the voter: sha256(salt + voter), shortened
+    ballot_id: String,
+    ranking: Vec<String>,
+    cast_at: DateTime<Utc>,
+    prev_hash: String,
+    hash: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BallotLog {
+    election: u64,
+    ballots: Vec<Ballot>,
+    // Every hash matches its contents and links to the previous entry
+    chain_valid: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Term {
+    holder: String,
+    election: u64,
+    starts: DateTime<Utc>,
+    ends: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Office {
+    // None while the office is vacant
+    current: Option<Term>,
+    // Already elected, waiting for the current term to end
+    next: Option<Term>,
+}
+
+fn ballot_hash(prev_hash: &str, ballot_id: &str, ranking: &[String], cast_at: &DateTime<Utc>) -> String {
+    let digest = Sha256::digest(format!("{}|{}|{}|{}", prev_hash, ballot_id, ranking.join(","), cast_at.to_rfc3339()));
+    format!("{:x}", digest)
+}
+
+fn new_salt(id: u64) -> String {
+    // Prefer the OS entropy pool; fall back to the clock where it is unavailable
+    let entropy = std::fs::File::open("/dev/urandom")
+        .and_then(|mut f| {
+            let mut buf = [0u8; 32];
+            std::io::Read::read_exact(&mut f, &mut buf)?;
+            Ok(buf.to_vec())
+        })
+        .unwrap_or_else(|_| Utc::now().timestamp_nanos_opt().unwrap_or_default().to_be_bytes().to_vec());
+    let mut hasher = Sha256::new();
+    hasher.update(id.to_be_bytes());
+    hasher.update(&entropy);
+    format!("{:x}", hasher.finalize())
+}
+
+impl Election {
+    // The nominated spelling of `name`; names match case-insensitively everywhere
+    fn nominee(&self, name: &str) -> Option<&str> {
+        self.candidates
+            .iter()
+            .map(|n| n.candidate.as_str())
+            .find(|c| c.eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+fn read_election(table: &impl ReadableTable<u64, &'static str>, id: u64) -> Result<Election, KernelError> {
+    match table.get(id)? {
+        Some(v) => Ok(serde_json::from_str(v.value())?),
+        None => Err(KernelError::not_found("election", id.to_string())),
+    }
+}
+
+fn read_terms(table: &impl ReadableTable<u64, &'static str>) -> Result<Vec<Term>, KernelError> {
+    let mut terms = Vec::new();
+    for item in table.iter()? {
+        let (_, value) = item?;
+        terms.push(serde_json::from_str(value.value())?);
+    }
+    Ok(terms)
+}
+
+fn office_at(terms: Vec<Term>, now: DateTime<Utc>) -> Office {
+    let mut office = Office { current: None, next: None };
+    for term in terms {
+        if term.starts <= now && now < term.ends {
+            office.current = Some(term);
+        } else if term.starts > now {
+            office.next = Some(term);
+        }
+    }
+    office
+}
+
+pub fn office(db: &Database) -> Result<Office, KernelError> {
+    let read_txn = db.begin_read()?;
+    let terms = match read_txn.open_table(TERMS_TABLE) {
+        Ok(t) => read_terms(&t)?,
+        Err(redb::TableError::TableDoesNotExist(_)) => Vec::new(),
+        Err(e) => return Err(e.into()),
+    };
+    Ok(office_at(terms, Utc::now()))
+}
+
+// Name of the Sarkin in office now, if any
+pub fn sitting_sarkin(db: &Database) -> Result<Option<String>, KernelError> {
+    Ok(office(db)?.current.map(|t| t.holder))
+}
+
+pub fn list_elections(db: &Database) -> Result<Vec<Election>, KernelError> {
+    let read_txn = db.begin_read()?;
+    let table = match read_txn.open_table(ELECTIONS_TABLE) {
+        Ok(t) => t,
+        Err(redb::TableError::TableDoesNotExist(_)) => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut elections = Vec::new();
+    for item in table.iter()? {
+        let (_, value) = item?;
+        elections.push(serde_json::from_str(value.value())?);
+    }
+    Ok(elections)
+}
+
+pub fn get_election(db: &Database, id: u64) -> Result<Election, KernelError> {
+    let read_txn = db.begin_read()?;
+    let table = match read_txn.open_table(ELECTIONS_TABLE) {
+        Ok(t) => t,
+        Err(redb::TableError::TableDoesNotExist(_)) => return Err(KernelError::not_found("election", id.to_string())),
+        Err(e) => return Err(e.into()),
+    };
+    read_election(&table, id)
+}
+
+pub fn open_election(db: &Database, method: Method) -> Result<Election, KernelError> {
+    let write_txn = db.begin_write()?;
+    let election = {
+        let mut elections = write_txn.open_table(ELECTIONS_TABLE)?;
+        for item in elections.iter()? {
+            let (_, value) = item?;
+            let other: Election = serde_json::from_str(value.value())?;
+            if other.phase != Phase::Closed {
+                return Err(KernelError::Conflict(format!(
+                    "Election {} is still {}; close it before opening another",
+                    other.id,
+                    other.phase.as_str()
+                )));
+            }
+        }
+        let terms = read_terms(&write_txn.open_table(TERMS_TABLE)?)?;
+        if let Some(next) = office_at(terms, Utc::now()).next {
+            return Err(KernelError::Conflict(format!(
+                "The next term is already decided ({} from {})",
+                next.holder,
+                next.starts.format("%Y-%m-%d")
+            )));
+        }
+
+        let id = elections.last()?.map(|(key, _)| key.value()).unwrap_or(0) + 1;
+        let election = Election {
+            id,
+            method,
+            phase: Phase::Nominating,
+            candidates: Vec::new(),
+            opened_at: Utc::now(),
+            voting_opened_at: None,
+            closed_at: None,
+            result: None,
+        };
+        let json = serde_json::to_string(&election)?;
+        elections.insert(id, json.as_str())?;
+        write_txn.open_table(SALTS_TABLE)?.insert(id, new_salt(id).as_str())?;
+        write_txn.open_table(BALLOTS_TABLE)?;
+        election
+    };
+    write_txn.commit()?;
+    Ok(election)
+}
+
+fn save(elections: &mut redb::Table<u64, &str>, election: &Election) -> Result<(), KernelError> {
+    let json = serde_json::to_string(election)?;
+    elections.insert(election.id, json.as_str())?;
+    Ok(())
+}
+
+fn require_phase(election: &Election, phase: Phase) -> Result<(), KernelError> {
+    if election.phase != phase {
+        return Err(KernelError::Conflict(format!(
+            "Election {} is {}, not {}",
+            election.id,
+            election.phase.as_str(),
+            phase.as_str()
+        )));
+    }
+    Ok(())
+}
+
+pub fn nominate(db: &Database, id: u64, candidate: &str, nominated_by: &str) -> Result<Election, KernelError> {
+    let candidate = candidate.trim();
+    let nominated_by = nominated_by.trim();
+    let mut problems = Vec::new();
+    if candidate.is_empty() {
+        problems.push("candidate must not be empty".to_string());
+    }
+    if nominated_by.is_empty() {
+        problems.push("nominated_by must not be empty".to_string());
+    }
+    if !problems.is_empty() {
+        return Err(KernelError::Validation(problems));
+    }
+
+    let write_txn = db.begin_write()?;
+    let election = {
+        let mut elections = write_txn.open_table(ELECTIONS_TABLE)?;
+        let mut election = read_election(&elections, id)?;
+        require_phase(&election, Phase::Nominating)?;
+
+        // Term Limits: the sitting Sarkin rotates out and cannot stand for the next term
+        let terms = read_terms(&write_txn.open_table(TERMS_TABLE)?)?;
+        if let Some(current) = office_at(terms, Utc::now()).current {
+            if current.holder.eq_ignore_ascii_case(candidate) {
+                return Err(KernelError::Validation(vec![format!(
+                    "{} holds the office until {} and may not serve consecutive terms",
+                    current.holder,
+                    current.ends.format("%Y-%m-%d")
+                )]));
+            }
+        }
+        if election.nominee(candidate).is_some() {
+            return Err(KernelError::Conflict(format!("{} is already nominated in election {}", candidate, id)));
+        }
+
+        election.candidates.push(Nomination {
+            candidate: candidate.to_string(),
+            nominated_by: nominated_by.to_string(),
+            nominated_at: Utc::now(),
+        });
+        save(&mut elections, &election)?;
+        election
+    };
+    write_txn.commit()?;
+    Ok(election)
+}
+
+pub fn start_voting(db: &Database, id: u64) -> Result<Election, KernelError> {
+    let write_txn = db.begin_write()?;
+    let election = {
+        let mut elections = write_txn.open_table(ELECTIONS_TABLE)?;
+        let mut election = read_election(&elections, id)?;
+        require_phase(&election, Phase::Nominating)?;
+        if election.candidates.is_empty() {
+            return Err(KernelError::Conflict(format!("Election {} has no candidates yet", id)));
+        }
+        election.phase = Phase::Voting;
+        election.voting_opened_at = Some(Utc::now());
+        save(&mut elections, &election)?;
+        election
+    };
+    write_txn.commit()?;
+    Ok(election)
+}
+
+// Records a ballot and returns its log entry (the voter's receipt)
+pub fn cast(db: &Database, id: u64, voter: &str, ranking: &[String]) -> Result<Ballot, KernelError> {
+    let write_txn = db.begin_write()?;
+    let ballot = {
+        let elections = write_txn.open_table(ELECTIONS_TABLE)?;
+        let election = read_election(&elections, id)?;
+        require_phase(&election, Phase::Voting)?;
+
+        let mut problems = Vec::new();
+        // Ballots carry each nominee's canonical spelling, so "amina" counts for "Amina"
+        let mut canonical: Vec<String> = Vec::new();
+        for choice in ranking.iter().map(|c| c.trim()) {
+            match election.nominee(choice) {
+                Some(name) if canonical.iter().any(|c| c == name) => {
+                    problems.push(format!("'{}' is ranked more than once", name))
+                }
+                Some(name) => canonical.push(name.to_string()),
+                None => problems.push(format!("'{}' is not a candidate in election {}", choice, id)),
+            }
+        }
+        if voter.trim().is_empty() {
+            problems.push("voter must not be empty".to_string());
+        }
+        match election.method {
+            Method::Plurality if ranking.len() != 1 => {
+                problems.push("plurality ballots name exactly one candidate".to_string())
+            }
+            Method::RankedChoice if ranking.is_empty() => {
+                problems.push("ranked-choice ballots rank at least one candidate".to_string())
+            }
+            _ => {}
+        }
+        if !problems.is_empty() {
+            return Err(KernelError::Validation(problems));
+        }
+
+        let salt = match write_txn.open_table(SALTS_TABLE)?.get(id)? {
+            Some(v) => v.value().to_string(),
+            None => return Err(KernelError::not_found("election", id.to_string())),
+        };
+        let digest = Sha256::digest(format!("{}:{}", salt, voter.trim().to_lowercase()));
+        let ballot_id = format!("{:x}", digest)[..16].to_string();
+
+        let mut ballots = write_txn.open_table(BALLOTS_TABLE)?;
+        let mut seq = 0;
+        let mut prev_hash = GENESIS_HASH.to_string();
+        for item in ballots.range((id, 0)..)? {
+            let (key, value) = item?;
+            if key.value().0 != id {
+                break;
+            }
+            let existing: Ballot = serde_json::from_str(value.value())?;
+            if existing.ballot_id == ballot_id {
+                return Err(KernelError::Conflict(format!("This voter has already voted in election {}", id)));
+            }
+            seq = existing.seq;
+            prev_hash = existing.hash;
+        }
+
+        let cast_at = Utc::now();
+        let ballot = Ballot {
+            seq: seq + 1,
+            hash: ballot_hash(&prev_hash, &ballot_id, &canonical, &cast_at),
+            ballot_id,
+            ranking: canonical,
+            cast_at,
+            prev_hash,
+        };
+        let json = serde_json::to_string(&ballot)?;
+        ballots.insert((id, ballot.seq), json.as_str())?;
+        ballot
+    };
+    write_txn.commit()?;
+    Ok(ballot)
+}
+
+// Reads an election's ballots and verifies their hash chain
+fn read_log(table: &impl ReadableTable<(u64, u64), &'static str>, id: u64) -> Result<BallotLog, KernelError> {
+    let mut ballots: Vec<Ballot> = Vec::new();
+    for item in table.range((id, 0)..)? {
+        let (key, value) = item?;
+        if key.value().0 != id {
+            break;
+        }
+        ballots.push(serde_json::from_str(value.value())?);
+    }
+
+    let mut prev = GENESIS_HASH;
+    let mut chain_valid = true;
+    for b in &ballots {
+        chain_valid &= b.prev_hash == prev && b.hash == ballot_hash(&b.prev_hash, &b.ballot_id, &b.ranking, &b.cast_at);
+        prev = &b.hash;
+    }
+    Ok(BallotLog { election: id, ballots, chain_valid })
+}
+
+pub fn ballot_log(db: &Database, id: u64) -> Result<BallotLog, KernelError> {
+    get_election(db, id)?;
+    let read_txn = db.begin_read()?;
+    let table = read_txn.open_table(BALLOTS_TABLE)?;
+    read_log(&table, id)
+}
+
+// Ties go to the candidate nominated first
+fn tally(method: Method, candidates: &[String], ballots: &[Vec<String>]) -> Tally {
+    let mut remaining: Vec<String> = candidates.to_vec();
+    let mut rounds = Vec::new();
+    let mut eliminated = Vec::new();
+
+    loop {
+        let mut counts: BTreeMap<String, usize> = remaining.iter().map(|c| (c.clone(), 0)).collect();
+        let mut exhausted = 0;
+        for ballot in ballots {
+            match ballot.iter().find(|c| remaining.contains(c)) {
+                Some(choice) => *counts.entry(choice.clone()).or_insert(0) += 1,
+                None => exhausted += 1,
+            }
+        }
+        let active = ballots.len() - exhausted;
+        let top = counts.values().copied().max().unwrap_or(0);
+        let leaders: Vec<&String> = remaining.iter().filter(|c| counts[*c] == top).collect();
+        rounds.push(counts.clone());
+
+        let decided = match method {
+            Method::Plurality => true,
+            Method::RankedChoice => top * 2 > active || remaining.len() <= 1,
+        };
+        if decided {
+            let tie_break = (leaders.len() > 1).then(|| {
+                format!("{} tied on {}; the earliest nomination wins", leaders.iter().map(|c| c.as_str()).collect::<Vec<_>>().join(", "), top)
+            });
+            return Tally {
+                winner: leaders[0].clone(),
+                ballots: ballots.len(),
+                rounds,
+                eliminated,
+                exhausted,
+                tie_break,
+            };
+        }
+
+        // Eliminate the weakest; among equals the latest nomination goes first
+        let bottom = counts.values().copied().min().unwrap_or(0);
+        if let Some(pos) = remaining.iter().rposition(|c| counts[c] == bottom) {
+            eliminated.push(remaining.remove(pos));
+        }
+    }
+}
+
+// Tallies the ballots and seats the winner for the next term
+pub fn close(db: &Database, id: u64) -> Result<(Election, Term), KernelError> {
+    let write_txn = db.begin_write()?;
+    let result = {
+        let mut elections = write_txn.open_table(ELECTIONS_TABLE)?;
+        let mut election = read_election(&elections, id)?;
+        require_phase(&election, Phase::Voting)?;
+        // Read in this transaction so no ballot can land between the tally and the close
+        let log = read_log(&write_txn.open_table(BALLOTS_TABLE)?, id)?;
+        if !log.chain_valid {
+            return Err(KernelError::Conflict(format!("The ballot log of election {} failed verification", id)));
+        }
+        if log.ballots.is_empty() {
+            return Err(KernelError::Conflict(format!("No ballots have been cast in election {}", id)));
+        }
+
+        let candidates: Vec<String> = election.candidates.iter().map(|n| n.candidate.clone()).collect();
+        let ballots: Vec<Vec<String>> = log.ballots.into_iter().map(|b| b.ranking).collect();
+        let result = tally(election.method, &candidates, &ballots);
+
+        // Terms never overlap: the new one starts when the sitting Sarkin's ends
+        let now = Utc::now();
+        let mut terms = write_txn.open_table(TERMS_TABLE)?;
+        let starts = office_at(read_terms(&terms)?, now).current.map_or(now, |t| t.ends);
+        let ends = starts
+            .checked_add_months(Months::new(TERM_MONTHS))
+            .ok_or_else(|| KernelError::Validation(vec!["term end is out of range".to_string()]))?;
+        let term = Term { holder: result.winner.clone(), election: id, starts, ends };
+        let json = serde_json::to_string(&term)?;
+        terms.insert(id, json.as_str())?;
+
+        election.phase = Phase::Closed;
+        election.closed_at = Some(now);
+        election.result = Some(result);
+        save(&mut elections, &election)?;
+        (election, term)
+    };
+    write_txn.commit()?;
+    Ok(result)
+}
+
+// --- CLI: `culture-kernel guild` ---
+
+#[derive(Subcommand, Debug)]
+pub enum GuildCommand {
+    /// Who holds the office now, and who is next
+    Sarkin,
+    /// List elections
+    Elections,
+    /// Open nominations for the next term
+    Open {
+        #[arg(long, value_enum, default_value_t = Method::RankedChoice)]
+        method: Method,
+    },
+    /// Nominate a candidate
+    Nominate {
+        election: u64,
+        candidate: String,
+        /// Who is making the nomination
+        #[arg(long = "by")]
+        nominated_by: String,
+    },
+    /// Close nominations and open the ballot box
+    StartVoting { election: u64 },
+    /// Cast a secret ballot (candidates in order of preference)
+    Vote {
+        election: u64,
+        #[arg(long)]
+        voter: String,
+        #[arg(required = true)]
+        ranking: Vec<String>,
+    },
+    /// Tally the ballots and seat the winner
+    Close { election: u64 },
+    /// Show an election with its candidates and result
+    Show { election: u64 },
+    /// Print the ballot log and verify its hash chain
+    Ballots { election: u64 },
+}
+
+fn print_term(label: &str, term: &Term) {
+    println!(
+        "{} {} ({} → {}, election {})",
+        label,
+        term.holder.green().bold(),
+        term.starts.format("%Y-%m-%d"),
+        term.ends.format("%Y-%m-%d"),
+        term.election
+    );
+}
+
+fn print_tally(t: &Tally) {
+    for (i, round) in t.rounds.iter().enumerate() {
+        let counts: Vec<String> = round.iter().map(|(c, n)| format!("{} {}", c, n)).collect();
+        println!("  round {}: {}", i + 1, counts.join(", "));
+    }
+    if !t.eliminated.is_empty() {
+        println!("  eliminated: {}", t.eliminated.join(" → "));
+    }
+    if let Some(tie) = &t.tie_break {
+        println!("  {}", tie.yellow());
+    }
+    println!("  winner: {} ({} ballots, {} exhausted)", t.winner.green().bold(), t.ballots, t.exhausted);
+}
+
+pub fn guild_cli(db: &Arc<Database>, command: &GuildCommand) -> anyhow::Result<()> {
+    match command {
+        GuildCommand::Sarkin => {
+            let office = office(db)?;
+            match &office.current {
+                Some(term) => print_term("Sarkin Code:", term),
+                None => println!("{} The office is vacant.", "Sarkin Code:".bold()),
+            }
+            if let Some(term) = &office.next {
+                print_term("Next:       ", term);
+            }
+        }
+        GuildCommand::Elections => {
+            let elections = list_elections(db)?;
+            if elections.is_empty() {
+                println!("No elections yet. Open one with 'culture-kernel guild open'.");
+            }
+            for e in elections {
+                println!(
+                    "#{:<3} {:<11} {:<13} {} candidate(s){}",
+                    e.id,
+                    e.phase.as_str(),
+                    e.method.as_str(),
+                    e.candidates.len(),
+                    e.result.map(|r| format!(", won by {}", r.winner.green())).unwrap_or_default()
+                );
+            }
+        }
+        GuildCommand::Open { method } => {
+            let e = open_election(db, *method)?;
+            println!("{} election {} ({}); nominations are open", "Opened".green().bold(), e.id, e.method.as_str());
+        }
+        GuildCommand::Nominate { election, candidate, nominated_by } => {
+            nominate(db, *election, candidate, nominated_by)?;
+            println!("{} {} in election {}", "Nominated".green().bold(), candidate.cyan(), election);
+        }
+        GuildCommand::StartVoting { election } => {
+            let e = start_voting(db, *election)?;
+            let names: Vec<&str> = e.candidates.iter().map(|n| n.candidate.as_str()).collect();
+            println!("{} election {}: {}", "Voting open".green().bold(), e.id, names.join(", "));
+        }
+        GuildCommand::Vote { election, voter, ranking } => {
+            let ballot = cast(db, *election, voter, ranking)?;
+            println!("{} ballot #{} (receipt {})", "Cast".green().bold(), ballot.seq, ballot.ballot_id.cyan());
+        }
+        GuildCommand::Close { election } => {
+            let (e, term) = close(db, *election)?;
+            println!("{} election {}", "Closed".green().bold(), e.id);
+            if let Some(result) = &e.result {
+                print_tally(result);
+            }
+            print_term("Term:", &term);
+        }
+        GuildCommand::Show { election } => {
+            let e = get_election(db, *election)?;
+            println!("Election #{} ({}, {})", e.id, e.method.as_str(), e.phase.as_str());
+            for n in &e.candidates {
+                println!("  {} (nominated by {})", n.candidate.cyan(), n.nominated_by);
+            }
+            if let Some(result) = &e.result {
+                print_tally(result);
+            }
+        }
+        GuildCommand::Ballots { election } => {
+            let log = ballot_log(db, *election)?;
+            for b in &log.ballots {
+                println!("#{:<3} {} {} [{}]", b.seq, b.ballot_id.cyan(), &b.hash[..16], b.ranking.join(" > "));
+            }
+            if log.chain_valid {
+                println!("{} {} ballot(s), hash chain intact", "VERIFIED".green().bold(), log.ballots.len());
+            } else {
+                println!("{} the ballot log has been altered", "TAMPERED".red().bold());
+            }
+        }
+    }
+    Ok(())
+}
+
+// --- API: /guild ---
+
+#[derive(Debug, Deserialize)]
+pub struct NewElection {
+    #[serde(default = "default_method")]
+    method: Method,
+}
+
+fn default_method() -> Method {
+    Method::RankedChoice
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NewNomination {
+    candidate: String,
+    nominated_by: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NewBallot {
+    voter: String,
+    // Plurality: exactly one name
+    ranking: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ClosedElection {
+    election: Election,
+    term: Term,
+}
+
+// GET /guild/sarkin
+pub async fn api_sarkin(State(db): State<Arc<Database>>) -> Result<Response, KernelError> {
+    Ok(Json(office(&db)?).into_response())
+}
+
+// GET /guild/elections
+pub async fn api_list(State(db): State<Arc<Database>>) -> Result<Response, KernelError> {
+    Ok(Json(list_elections(&db)?).into_response())
+}
+
+// POST /guild/elections
+pub async fn api_open(
+    State(db): State<Arc<Database>>,
+    body: Result<Json<NewElection>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(new) = body?;
+    let election = open_election(&db, new.method)?;
+    Ok((StatusCode::CREATED, Json(election)).into_response())
+}
+
+// GET /guild/elections/{id}
+pub async fn api_get(State(db): State<Arc<Database>>, Path(id): Path<u64>) -> Result<Response, KernelError> {
+    Ok(Json(get_election(&db, id)?).into_response())
+}
+
+// POST /guild/elections/{id}/nominations
+pub async fn api_nominate(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<u64>,
+    body: Result<Json<NewNomination>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(new) = body?;
+    let election = nominate(&db, id, &new.candidate, &new.nominated_by)?;
+    Ok((StatusCode::CREATED, Json(election)).into_response())
+}
+
+// POST /guild/elections/{id}/voting
+pub async fn api_start_voting(State(db): State<Arc<Database>>, Path(id): Path<u64>) -> Result<Response, KernelError> {
+    Ok(Json(start_voting(&db, id)?).into_response())
+}
+
+// POST /guild/elections/{id}/ballots
+pub async fn api_cast(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<u64>,
+    body: Result<Json<NewBallot>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(new) = body?;
+    let ballot = cast(&db, id, &new.voter, &new.ranking)?;
+    Ok((StatusCode::CREATED, Json(ballot)).into_response())
+}
+
+// GET /guild/elections/{id}/ballots
+pub async fn api_ballots(State(db): State<Arc<Database>>, Path(id): Path<u64>) -> Result<Response, KernelError> {
+    Ok(Json(ballot_log(&db, id)?).into_response())
+}
+
+// POST /guild/elections/{id}/close
+pub async fn api_close(State(db): State<Arc<Database>>, Path(id): Path<u64>) -> Result<Response, KernelError> {
+    let (election, term) = close(&db, id)?;
+    Ok(Json(ClosedElection { election, term }).into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn ballots(list: &[&[&str]]) -> Vec<Vec<String>> {
+        list.iter().map(|b| names(b)).collect()
+    }
+
+    #[test]
+    fn plurality_counts_first_choices_and_breaks_ties_by_nomination_order() {
+        let t = tally(Method::Plurality, &names(&["Amina", "Bello"]), &ballots(&[&["Bello"], &["Amina"], &["Bello"]]));
+        assert_eq!(t.winner, "Bello");
+        assert_eq!(t.rounds.len(), 1);
+        assert!(t.tie_break.is_none());
+
+        let t = tally(Method::Plurality, &names(&["Amina", "Bello"]), &ballots(&[&["Bello"], &["Amina"]]));
+        assert_eq!(t.winner, "Amina");
+        assert!(t.tie_break.is_some());
+    }
+
+    #[test]
+    fn ranked_choice_majority_in_the_first_round() {
+        let t = tally(
+            Method::RankedChoice,
+            &names(&["Amina", "Bello", "Chidi"]),
+            &ballots(&[&["Chidi"], &["Chidi", "Amina"], &["Amina"]]),
+        );
+        assert_eq!(t.winner, "Chidi");
+        assert_eq!(t.rounds.len(), 1);
+        assert!(t.eliminated.is_empty());
+    }
+
+    #[test]
+    fn ranked_choice_transfers_eliminated_votes() {
+        let t = tally(
+            Method::RankedChoice,
+            &names(&["Amina", "Bello", "Chidi"]),
+            &ballots(&[&["Amina"], &["Amina"], &["Bello"], &["Bello"], &["Chidi", "Bello"]]),
+        );
+        assert_eq!(t.winner, "Bello");
+        assert_eq!(t.eliminated, ["Chidi"]);
+        assert_eq!(t.rounds[1]["Bello"], 3);
+        assert_eq!(t.exhausted, 0);
+    }
+
+    #[test]
+    fn ranked_choice_all_tied_round_keeps_eliminating() {
+        let t = tally(
+            Method::RankedChoice,
+            &names(&["Amina", "Bello", "Chidi"]),
+            &ballots(&[&["Chidi"], &["Bello"], &["Amina"]]),
+        );
+        assert_eq!(t.eliminated, ["Chidi", "Bello"]);
+        assert_eq!(t.rounds.len(), 3);
+        assert_eq!(t.exhausted, 2);
+        assert_eq!(t.winner, "Amina");
+    }
+
+    #[test]
+    fn ranked_choice_tied_first_round_transfers_to_a_majority() {
+        let t = tally(
+            Method::RankedChoice,
+            &names(&["Amina", "Bello", "Chidi"]),
+            &ballots(&[&["Chidi", "Bello"], &["Bello"], &["Amina"]]),
+        );
+        assert_eq!(t.eliminated, ["Chidi"]);
+        assert_eq!(t.rounds[1]["Bello"], 2);
+        assert_eq!(t.winner, "Bello");
+        assert!(t.tie_break.is_none());
+    }
+
+    #[test]
+    fn ranked_choice_eliminates_latest_nominee_first_and_counts_exhausted_ballots() {
+        let t = tally(
+            Method::RankedChoice,
+            &names(&["Amina", "Bello", "Chidi", "Dauda"]),
+            &ballots(&[
+                &["Amina"],
+                &["Amina"],
+                &["Amina"],
+                &["Bello"],
+                &["Bello"],
+                &["Chidi", "Bello"],
+                &["Dauda"],
+            ]),
+        );
+        // Chidi and Dauda tie at the bottom and Dauda, nominated last, goes first; the
+        // Amina/Bello tie in round three is broken the same way
+        assert_eq!(t.eliminated, ["Dauda", "Chidi", "Bello"]);
+        assert_eq!(t.rounds[2]["Amina"], 3);
+        assert_eq!(t.rounds[2]["Bello"], 3);
+        assert_eq!(t.rounds.len(), 4);
+        assert_eq!(t.exhausted, 4);
+        assert_eq!(t.winner, "Amina");
+    }
+
+    #[test]
+    fn nominees_match_case_insensitively() {
+        let election = Election {
+            id: 1,
+            method: Method::RankedChoice,
+            phase: Phase::Voting,
+            candidates: vec![Nomination {
+                candidate: "Amina".to_string(),
+                nominated_by: "Tunde".to_string(),
+                nominated_at: Utc::now(),
+            }],
+            opened_at: Utc::now(),
+            voting_opened_at: None,
+            closed_at: None,
+            result: None,
+        };
+        assert_eq!(election.nominee(" amina "), Some("Amina"));
+        assert_eq!(election.nominee("Bello"), None);
+    }
+}
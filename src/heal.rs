@@ -0,0 +1,166 @@
+// --- SELF-HEALING STARTUP ---
+// Before serving, the kernel checks RITUALS_TABLE against the seed manifest
+// (the configured packs, or the embedded library without any): how many records parse, which
+// core protocols are missing, and which ones drifted from the manifest.
+// In `repair` mode unparsable records are quarantined and missing protocols restored.
+
+use clap::ValueEnum;
+use colored::*;
+use redb::{Database, ReadableTable};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::error::KernelError;
+use crate::{index, quarantine, seed, Ritual, RITUALS_TABLE};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum HealMode {
+    /// Skip the integrity pass entirely
+    Off,
+    /// Check and log problems, change nothing
+    Report,
+    /// Quarantine unparsable records and restore missing core protocols
+    Repair,
+}
+
+#[derive(Debug, Default)]
+pub struct HealReport {
+    stored: usize,
+    parseable: usize,
+    unparsable: Vec<String>,
+    manifest: usize,
+    // In the manifest, absent from the table
+    missing: Vec<String>,
+    // Present in both, but edited since seeding (left alone: runtime edits are intentional)
+    drifted: Vec<String>,
+    // Stored rituals that are not part of the manifest (team-specific protocols)
+    custom: usize,
+    quarantined: usize,
+    restored: usize,
+}
+
+impl HealReport {
+    fn healthy(&self) -> bool {
+        self.unparsable.is_empty() && self.missing.is_empty()
+    }
+}
+
+fn stored_records(db: &Database) -> Result<Vec<(String, String)>, KernelError> {
+    let read_txn = db.begin_read()?;
+    let table = match read_txn.open_table(RITUALS_TABLE) {
+        Ok(t) => t,
+        Err(redb::TableError::TableDoesNotExist(_)) => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let records = table
+        .iter()?
+        .map(|item| item.map(|(k, v)| (k.value().to_string(), v.value().to_string())))
+        .collect::<Result<_, _>>()?;
+    Ok(records)
+}
+
+pub fn check(db: &Database, manifest: &[Ritual]) -> Result<HealReport, KernelError> {
+    let records = stored_records(db)?;
+    let mut report = HealReport {
+        stored: records.len(),
+        manifest: manifest.len(),
+        ..Default::default()
+    };
+
+    let mut parsed: BTreeMap<String, Ritual> = BTreeMap::new();
+    for (id, raw) in records {
+        match quarantine::parse_stored(&id, &raw) {
+            Ok(ritual) => {
+                parsed.insert(id, ritual);
+            }
+            Err(_) => report.unparsable.push(id),
+        }
+    }
+    report.parseable = parsed.len();
+
+    for core in manifest {
+        match parsed.remove(&core.id) {
+            None => report.missing.push(core.id.clone()),
+            Some(stored) => {
+                if serde_json::to_value(&stored)? != serde_json::to_value(core)? {
+                    report.drifted.push(core.id.clone());
+                }
+            }
+        }
+    }
+    report.custom = parsed.len();
+    Ok(report)
+}
+
+// Inserts the given manifest rituals, skipping any id that reappeared meanwhile
+fn restore_missing(db: &Database, manifest: &[Ritual], missing: &[String]) -> Result<usize, KernelError> {
+    let write_txn = db.begin_write()?;
+    let mut restored = 0;
+    for ritual in manifest.iter().filter(|r| missing.contains(&r.id)) {
+        let exists = write_txn.open_table(RITUALS_TABLE)?.get(ritual.id.as_str())?.is_some();
+        if exists {
+            continue;
+        }
+        index::put_ritual(&write_txn, ritual)?;
+        restored += 1;
+    }
+    write_txn.commit()?;
+    Ok(restored)
+}
+
+pub fn run(db: &Database, seed_files: &[PathBuf], mode: HealMode) -> anyhow::Result<()> {
+    if mode == HealMode::Off {
+        return Ok(());
+    }
+
+    let manifest = seed::read_seed_sources(seed_files)?;
+    let mut report = check(db, &manifest)?;
+
+    if mode == HealMode::Repair && !report.healthy() {
+        report.quarantined = quarantine::scan(db)?;
+        report.restored = restore_missing(db, &manifest, &report.missing)?;
+    }
+
+    print_report(&report, mode);
+    Ok(())
+}
+
+fn print_report(report: &HealReport, mode: HealMode) {
+    let tag = "HEAL".cyan().bold();
+    println!(
+        "{} {} stored, {} parseable, {} in seed manifest, {} custom",
+        tag, report.stored, report.parseable, report.manifest, report.custom
+    );
+
+    if report.healthy() {
+        println!("{} {}", tag, "Integrity check passed.".green());
+    }
+    if !report.unparsable.is_empty() {
+        println!("{} {} unparsable: {}", tag, "✖".red(), report.unparsable.join(", "));
+    }
+    if !report.missing.is_empty() {
+        println!("{} {} missing core protocols: {}", tag, "✖".red(), report.missing.join(", "));
+    }
+    if !report.drifted.is_empty() {
+        println!(
+            "{} {} edited since seeding (kept): {}",
+            tag,
+            "~".yellow(),
+            report.drifted.join(", ")
+        );
+    }
+
+    match mode {
+        HealMode::Repair if !report.healthy() => println!(
+            "{} {} quarantined, {} restored",
+            tag,
+            report.quarantined.to_string().yellow().bold(),
+            report.restored.to_string().green().bold()
+        ),
+        HealMode::Report if !report.healthy() => {
+            println!("{} Report only; restart with --heal=repair to fix.", tag)
+        }
+        _ => {}
+    }
+}
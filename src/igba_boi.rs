@@ -0,0 +1,419 @@
+// --- IGBA BOI SETTLEMENT ESCROW ---
+// Executable form of IGBO_01_IGBA_BOI's Settlement Agreement. Each completed month a
+// Shadow Bonus (20% of base salary) plus a corporate match accrues in the settlement
+// escrow. The escrow vests linearly over the 48-month term. The final 12 months only
+// vest once the employee has trained a successor (Nwa Boi) inside that window. An
+// amicable exit before 48 months settles the vested share; any other early exit
+// forfeits the escrow. Base salary may rise during the term but is never reduced.
+
+use axum::{
+    extract::{rejection::JsonRejection, Path, State},
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{Months, NaiveDate};
+use clap::Args;
+use colored::*;
+use redb::Database;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::error::KernelError;
+use crate::load_ritual;
+
+pub const RITUAL_ID: &str = "IGBO_01_IGBA_BOI";
+
+const SHADOW_BONUS_RATE: f64 = 0.20;
+const TERM_MONTHS: u32 = 48;
+// The successor must be trained during the last SUCCESSOR_WINDOW_MONTHS of the term
+const SUCCESSOR_WINDOW_MONTHS: u32 = 12;
+const MAX_MATCH_RATIO: f64 = 10.0;
+
+// A raise taking effect on a given date (annual figure)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SalaryChange {
+    pub effective: NaiveDate,
+    pub annual_salary: f64,
+}
+
+// "2027-01-01=95000" on the command line
+impl FromStr for SalaryChange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (date, amount) = s.split_once('=').ok_or("expected DATE=ANNUAL_SALARY, e.g. 2027-01-01=95000")?;
+        Ok(SalaryChange {
+            effective: date.trim().parse().map_err(|e| format!("invalid date '{}': {}", date, e))?,
+            annual_salary: amount.trim().parse().map_err(|e| format!("invalid salary '{}': {}", amount, e))?,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SimulationRequest {
+    // Base salary at hire, per year
+    pub annual_salary: f64,
+    // Corporate match per unit of Shadow Bonus (1.0 = matched one-to-one)
+    #[serde(default = "default_match_ratio")]
+    pub match_ratio: f64,
+    pub start_date: NaiveDate,
+    // Defaults to the end of the 48-month term
+    pub exit_date: Option<NaiveDate>,
+    #[serde(default = "default_amicable")]
+    pub amicable: bool,
+    // When the Nwa Boi (successor) completed training
+    pub successor_trained_on: Option<NaiveDate>,
+    // Market rate for the role; the base salary must meet it
+    pub market_salary: Option<f64>,
+    #[serde(default)]
+    pub salary_changes: Vec<SalaryChange>,
+}
+
+fn default_match_ratio() -> f64 {
+    1.0
+}
+
+fn default_amicable() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExitKind {
+    // Served the full term
+    Completed,
+    Amicable,
+    NotAmicable,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LedgerMonth {
+    pub month: u32,
+    pub date: NaiveDate,
+    pub base_salary: f64,
+    pub shadow_bonus: f64,
+    pub corporate_match: f64,
+    pub escrow_balance: f64,
+    pub vested_percent: f64,
+    pub vested_amount: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SuccessorCondition {
+    pub window_start: NaiveDate,
+    pub window_end: NaiveDate,
+    pub trained_on: Option<NaiveDate>,
+    pub met: bool,
+    pub detail: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Simulation {
+    pub ritual_id: &'static str,
+    pub start_date: NaiveDate,
+    pub exit_date: NaiveDate,
+    pub exit: ExitKind,
+    pub months_served: u32,
+    pub term_months: u32,
+    pub shadow_bonus_rate: f64,
+    pub match_ratio: f64,
+    pub ledger: Vec<LedgerMonth>,
+    pub escrow_balance: f64,
+    pub vested_percent: f64,
+    pub settlement: f64,
+    pub forfeited: f64,
+    pub successor: SuccessorCondition,
+}
+
+fn cents(amount: f64) -> f64 {
+    (amount * 100.0).round() / 100.0
+}
+
+fn validate(request: &SimulationRequest) -> Vec<String> {
+    let mut problems = Vec::new();
+    if !request.annual_salary.is_finite() || request.annual_salary <= 0.0 {
+        problems.push("annual_salary must be greater than 0".to_string());
+    }
+    if !request.match_ratio.is_finite() || !(0.0..=MAX_MATCH_RATIO).contains(&request.match_ratio) {
+        problems.push(format!("match_ratio must be between 0 and {}", MAX_MATCH_RATIO));
+    }
+    if request.exit_date.is_some_and(|exit| exit < request.start_date) {
+        problems.push("exit_date must not be before start_date".to_string());
+    }
+
+    // Guardrail: No Indentured Labor. The escrow is a bonus layer, never a salary substitute.
+    if let Some(market) = request.market_salary {
+        if request.annual_salary < market {
+            problems.push(format!(
+                "annual_salary {:.2} is below market_salary {:.2}; the escrow must sit on top of a market salary",
+                request.annual_salary, market
+            ));
+        }
+    }
+    let mut changes = request.salary_changes.clone();
+    changes.sort_by_key(|c| c.effective);
+    let mut current = request.annual_salary;
+    for change in &changes {
+        if change.effective < request.start_date {
+            problems.push(format!("salary change on {} is before start_date", change.effective));
+        } else if !change.annual_salary.is_finite() || change.annual_salary < current {
+            problems.push(format!(
+                "salary change on {} would reduce base salary from {:.2} to {:.2}; base salary may never be reduced",
+                change.effective, current, change.annual_salary
+            ));
+        } else {
+            current = change.annual_salary;
+        }
+    }
+    problems
+}
+
+pub fn simulate(request: &SimulationRequest) -> Result<Simulation, KernelError> {
+    let problems = validate(request);
+    if !problems.is_empty() {
+        return Err(KernelError::Validation(problems));
+    }
+
+    let start = request.start_date;
+    let month_date = |m: u32| start.checked_add_months(Months::new(m));
+    let invalid = || KernelError::Validation(vec!["start_date is out of range".to_string()]);
+    let term_end = month_date(TERM_MONTHS).ok_or_else(invalid)?;
+    let window_start = month_date(TERM_MONTHS - SUCCESSOR_WINDOW_MONTHS).ok_or_else(invalid)?;
+    let exit_date = request.exit_date.unwrap_or(term_end).min(term_end);
+
+    // Only completed months accrue
+    let months_served = (1..=TERM_MONTHS)
+        .take_while(|m| month_date(*m).is_some_and(|d| d <= exit_date))
+        .count() as u32;
+    let exit = if months_served == TERM_MONTHS {
+        ExitKind::Completed
+    } else if request.amicable {
+        ExitKind::Amicable
+    } else {
+        ExitKind::NotAmicable
+    };
+
+    let trained_on = request.successor_trained_on;
+    let met = trained_on.is_some_and(|d| d >= window_start && d <= term_end && d <= exit_date);
+    let detail = match trained_on {
+        _ if met => "successor trained in the final 12 months; the full term can vest".to_string(),
+        Some(d) if d < window_start => format!("successor trained on {}, before the final-12-month window opened", d),
+        Some(d) => format!("successor training on {} falls after the exit date or term", d),
+        None => format!(
+            "train a successor between {} and {} to unlock the final {} months",
+            window_start, term_end, SUCCESSOR_WINDOW_MONTHS
+        ),
+    };
+
+    let mut changes = request.salary_changes.clone();
+    changes.sort_by_key(|c| c.effective);
+
+    let mut ledger = Vec::new();
+    let mut balance = 0.0;
+    let mut vested_percent = 0.0;
+    for month in 1..=months_served {
+        let date = month_date(month - 1).ok_or_else(invalid)?;
+        let completed = month_date(month).ok_or_else(invalid)?;
+        let annual = changes
+            .iter()
+            .rev()
+            .find(|c| c.effective <= date)
+            .map_or(request.annual_salary, |c| c.annual_salary);
+        let base_salary = annual / 12.0;
+        let shadow_bonus = base_salary * SHADOW_BONUS_RATE;
+        let corporate_match = shadow_bonus * request.match_ratio;
+        balance += shadow_bonus + corporate_match;
+
+        // The final months only unlock from the month the successor was trained in
+        let trained = met && trained_on.is_some_and(|d| d <= completed);
+        let unlocked = if trained { month } else { month.min(TERM_MONTHS - SUCCESSOR_WINDOW_MONTHS) };
+        vested_percent = f64::from(unlocked) / f64::from(TERM_MONTHS) * 100.0;
+        ledger.push(LedgerMonth {
+            month,
+            date,
+            base_salary: cents(base_salary),
+            shadow_bonus: cents(shadow_bonus),
+            corporate_match: cents(corporate_match),
+            escrow_balance: cents(balance),
+            vested_percent: cents(vested_percent),
+            vested_amount: cents(balance * vested_percent / 100.0),
+        });
+    }
+
+    // Training after the last completed month but before the exit still counts at settlement
+    if met {
+        vested_percent = f64::from(months_served) / f64::from(TERM_MONTHS) * 100.0;
+    }
+    // Guardrail: Clear Exit Terms. Only an amicable early exit keeps the vested share.
+    if exit == ExitKind::NotAmicable {
+        vested_percent = 0.0;
+    }
+    let settlement = balance * vested_percent / 100.0;
+
+    Ok(Simulation {
+        ritual_id: RITUAL_ID,
+        start_date: start,
+        exit_date,
+        exit,
+        months_served,
+        term_months: TERM_MONTHS,
+        shadow_bonus_rate: SHADOW_BONUS_RATE,
+        match_ratio: request.match_ratio,
+        ledger,
+        escrow_balance: cents(balance),
+        vested_percent: cents(vested_percent),
+        settlement: cents(settlement),
+        forfeited: cents(balance - settlement),
+        successor: SuccessorCondition { window_start, window_end: term_end, trained_on, met, detail },
+    })
+}
+
+// --- CLI: `culture-kernel igba-boi` ---
+
+#[derive(Args, Debug)]
+pub struct IgbaBoiArgs {
+    /// Base salary per year at hire
+    #[arg(long)]
+    salary: f64,
+    /// Corporate match per unit of Shadow Bonus (1.0 = one-to-one)
+    #[arg(long, default_value_t = 1.0)]
+    match_ratio: f64,
+    /// Hire date (YYYY-MM-DD)
+    #[arg(long)]
+    start: NaiveDate,
+    /// Exit date; defaults to the end of the 48-month term
+    #[arg(long)]
+    exit: Option<NaiveDate>,
+    /// The employee left on bad terms (forfeits the escrow before 48 months)
+    #[arg(long)]
+    not_amicable: bool,
+    /// Date the successor (Nwa Boi) completed training
+    #[arg(long)]
+    successor_trained_on: Option<NaiveDate>,
+    /// Market rate for the role; the base salary must meet it
+    #[arg(long)]
+    market_salary: Option<f64>,
+    /// A raise as DATE=ANNUAL_SALARY (repeatable); reductions are rejected
+    #[arg(long = "raise")]
+    raises: Vec<SalaryChange>,
+    /// Print every month instead of one row per year
+    #[arg(long)]
+    ledger: bool,
+}
+
+pub fn igba_boi_cli(args: &IgbaBoiArgs) -> anyhow::Result<()> {
+    let sim = simulate(&SimulationRequest {
+        annual_salary: args.salary,
+        match_ratio: args.match_ratio,
+        start_date: args.start,
+        exit_date: args.exit,
+        amicable: !args.not_amicable,
+        successor_trained_on: args.successor_trained_on,
+        market_salary: args.market_salary,
+        salary_changes: args.raises.clone(),
+    })?;
+
+    println!("{} {}", "SETTLEMENT ESCROW".yellow().bold(), RITUAL_ID.cyan());
+    println!(
+        "  {} → {} ({} of {} months, {})",
+        sim.start_date,
+        sim.exit_date,
+        sim.months_served,
+        sim.term_months,
+        match sim.exit {
+            ExitKind::Completed => "term completed".green().to_string(),
+            ExitKind::Amicable => "amicable exit".yellow().to_string(),
+            ExitKind::NotAmicable => "non-amicable exit".red().to_string(),
+        }
+    );
+    println!(
+        "  Shadow Bonus {:.0}% of base salary, corporate match {}x\n",
+        sim.shadow_bonus_rate * 100.0,
+        sim.match_ratio
+    );
+
+    println!(
+        "  {:>5}  {:<10}  {:>10}  {:>10}  {:>10}  {:>12}  {:>7}",
+        "MONTH", "DATE", "BASE", "BONUS", "MATCH", "ESCROW", "VESTED"
+    );
+    for m in &sim.ledger {
+        // Yearly rows plus the last one, unless the whole ledger was asked for
+        if !args.ledger && m.month % 12 != 0 && m.month != sim.months_served {
+            continue;
+        }
+        println!(
+            "  {:>5}  {:<10}  {:>10.2}  {:>10.2}  {:>10.2}  {:>12.2}  {:>6.1}%",
+            m.month, m.date, m.base_salary, m.shadow_bonus, m.corporate_match, m.escrow_balance, m.vested_percent
+        );
+    }
+
+    println!();
+    println!("  Escrow balance: {:.2}", sim.escrow_balance);
+    println!("  Vested:         {:.1}%", sim.vested_percent);
+    println!("  {} {:.2}", "Settlement:    ".green().bold(), sim.settlement);
+    if sim.forfeited > 0.0 {
+        println!("  Forfeited:      {:.2}", sim.forfeited);
+    }
+    let tag = if sim.successor.met { "✔".green() } else { "✖".red() };
+    println!("  Successor:      {} {}", tag, sim.successor.detail);
+    Ok(())
+}
+
+// --- API: POST /rituals/{id}/simulate ---
+
+// Only rituals with an executable model can be simulated
+pub async fn api_simulate(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<String>,
+    body: Result<Json<SimulationRequest>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    if load_ritual(&db, &id)?.is_none() {
+        return Err(KernelError::not_found("ritual", &id));
+    }
+    if id != RITUAL_ID {
+        return Err(KernelError::not_found("simulator", &id));
+    }
+    let Json(request) = body?;
+    Ok(Json(simulate(&request)?).into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(successor_trained_on: Option<NaiveDate>) -> SimulationRequest {
+        SimulationRequest {
+            annual_salary: 120_000.0,
+            match_ratio: 1.0,
+            start_date: NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+            exit_date: None,
+            amicable: true,
+            successor_trained_on,
+            market_salary: None,
+            salary_changes: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn final_months_unlock_from_the_training_month() {
+        // Month 41 completes on 2029-06-01, month 42 on 2029-07-01
+        let sim = simulate(&request(NaiveDate::from_ymd_opt(2029, 6, 15))).unwrap();
+        assert!(sim.successor.met);
+        let month = |m: u32| &sim.ledger[m as usize - 1];
+        assert_eq!(month(36).vested_percent, 75.0);
+        assert_eq!(month(41).vested_percent, 75.0);
+        assert_eq!(month(41).vested_amount, cents(month(41).escrow_balance * 0.75));
+        assert_eq!(month(42).vested_percent, 87.5);
+        assert_eq!(month(48).vested_percent, 100.0);
+        assert_eq!(sim.settlement, sim.escrow_balance);
+    }
+
+    #[test]
+    fn final_months_stay_locked_without_a_successor() {
+        let sim = simulate(&request(None)).unwrap();
+        assert!(!sim.successor.met);
+        assert!(sim.ledger.iter().skip(36).all(|m| m.vested_percent == 75.0));
+        assert_eq!(sim.vested_percent, 75.0);
+    }
+}
@@ -0,0 +1,769 @@
+// --- PULAAKU INCIDENT MODE ---
+// Executable form of HAUSA_08_PULAAKU for Severity-1 incidents. An incident is
+// declared with a leader and a responder list, then moves active -> resolved -> closed.
+//   Hakkilo: every command goes into an append-only log *before* it runs; an entry
+//            whose execution time is not in the future is rejected.
+//   Silence of the Herder: only responders may post commands and notes (terse ones).
+//   Psychological Safety: anyone may raise a concern; it is never gated.
+//   Decompression: an incident cannot close until a warm retrospective (how people
+//            felt, not only what broke) is on record. The leader checks in last.
+
+use axum::{
+    extract::{rejection::JsonRejection, Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use clap::Subcommand;
+use colored::*;
+use redb::{Database, ReadableTable, TableDefinition};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::error::KernelError;
+
+// incident id -> Incident JSON
+const INCIDENTS_TABLE: TableDefinition<u64, &str> = TableDefinition::new("incidents");
+// (incident id, entry seq) -> LogEntry JSON; entries are only ever inserted
+const LOG_TABLE: TableDefinition<(u64, u64), &str> = TableDefinition::new("incident_log");
+
+// Munyal: notes stay terse
+const MAX_NOTE_CHARS: usize = 280;
+// CLI default when a command is logged without --at
+const DEFAULT_LEAD_SECONDS: i64 = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Active,
+    Resolved,
+    Closed,
+}
+
+impl Status {
+    fn as_str(self) -> &'static str {
+        match self {
+            Status::Active => "active",
+            Status::Resolved => "resolved",
+            Status::Closed => "closed",
+        }
+    }
+}
+
+impl std::str::FromStr for Status {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "active" => Ok(Status::Active),
+            "resolved" => Ok(Status::Resolved),
+            "closed" => Ok(Status::Closed),
+            other => Err(format!("unknown status '{}' (active, resolved, closed)", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EntryKind {
+    // Announced before it runs
+    Command,
+    // Terse, factual status from a responder
+    Note,
+    // Safety or ethics concern from anyone
+    Concern,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogEntry {
+    seq: u64,
+    kind: EntryKind,
+    author: String,
+    text: String,
+    logged_at: DateTime<Utc>,
+    // Commands only: when the command will be run
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    execute_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CheckIn {
+    name: String,
+    // How the person is doing after the incident
+    feeling: String,
+}
+
+// "Name=feeling", as taken by `incidents retro --check-in`
+impl std::str::FromStr for CheckIn {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, feeling) = s.split_once('=').ok_or_else(|| format!("'{}' is not NAME=FEELING", s))?;
+        Ok(CheckIn { name: name.trim().to_string(), feeling: feeling.trim().to_string() })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Retrospective {
+    facilitator: String,
+    held_at: DateTime<Utc>,
+    check_ins: Vec<CheckIn>,
+    learnings: Vec<String>,
+    #[serde(default)]
+    follow_ups: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Incident {
+    id: u64,
+    title: String,
+    leader: String,
+    // Always includes the leader
+    responders: Vec<String>,
+    declared_at: DateTime<Utc>,
+    status: Status,
+    resolution: Option<String>,
+    resolved_at: Option<DateTime<Utc>>,
+    retrospective: Option<Retrospective>,
+    closed_at: Option<DateTime<Utc>>,
+}
+
+impl Incident {
+    fn is_responder(&self, name: &str) -> bool {
+        self.responders.iter().any(|r| r.eq_ignore_ascii_case(name.trim()))
+    }
+
+    fn is_leader(&self, name: &str) -> bool {
+        self.leader.eq_ignore_ascii_case(name.trim())
+    }
+
+    fn require_status(&self, status: Status) -> Result<(), KernelError> {
+        if self.status != status {
+            return Err(KernelError::Conflict(format!(
+                "Incident {} is {}, not {}",
+                self.id,
+                self.status.as_str(),
+                status.as_str()
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NewIncident {
+    title: String,
+    leader: String,
+    #[serde(default)]
+    responders: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NewResponder {
+    name: String,
+    // Only the leader adds responders
+    added_by: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NewEntry {
+    kind: EntryKind,
+    author: String,
+    text: String,
+    // Required for commands
+    execute_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Resolution {
+    // The leader
+    by: String,
+    summary: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NewRetrospective {
+    facilitator: String,
+    check_ins: Vec<CheckIn>,
+    learnings: Vec<String>,
+    #[serde(default)]
+    follow_ups: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct IncidentFilter {
+    status: Option<String>,
+}
+
+fn read_incident(table: &impl ReadableTable<u64, &'static str>, id: u64) -> Result<Incident, KernelError> {
+    match table.get(id)? {
+        Some(v) => Ok(serde_json::from_str(v.value())?),
+        None => Err(KernelError::not_found("incident", id.to_string())),
+    }
+}
+
+pub fn declare(db: &Database, new: NewIncident) -> Result<Incident, KernelError> {
+    let leader = new.leader.trim().to_string();
+    let mut problems = Vec::new();
+    if new.title.trim().is_empty() {
+        problems.push("title must not be empty".to_string());
+    }
+    if leader.is_empty() {
+        problems.push("leader must not be empty".to_string());
+    }
+    if new.responders.iter().any(|r| r.trim().is_empty()) {
+        problems.push("responders must not contain empty names".to_string());
+    }
+    if !problems.is_empty() {
+        return Err(KernelError::Validation(problems));
+    }
+
+    let mut responders = vec![leader.clone()];
+    for r in &new.responders {
+        if !responders.iter().any(|known| known.eq_ignore_ascii_case(r.trim())) {
+            responders.push(r.trim().to_string());
+        }
+    }
+
+    let write_txn = db.begin_write()?;
+    let incident = {
+        let mut table = write_txn.open_table(INCIDENTS_TABLE)?;
+        let id = table.last()?.map(|(key, _)| key.value()).unwrap_or(0) + 1;
+        let incident = Incident {
+            id,
+            title: new.title.trim().to_string(),
+            leader,
+            responders,
+            declared_at: Utc::now(),
+            status: Status::Active,
+            resolution: None,
+            resolved_at: None,
+            retrospective: None,
+            closed_at: None,
+        };
+        let json = serde_json::to_string(&incident)?;
+        table.insert(id, json.as_str())?;
+        write_txn.open_table(LOG_TABLE)?;
+        incident
+    };
+    write_txn.commit()?;
+    Ok(incident)
+}
+
+// Incidents, most recently declared first
+pub fn list(db: &Database, filter: &IncidentFilter) -> Result<Vec<Incident>, KernelError> {
+    let status = match filter.status.as_deref() {
+        Some(s) => Some(s.parse::<Status>().map_err(|e| KernelError::Validation(vec![e]))?),
+        None => None,
+    };
+
+    let read_txn = db.begin_read()?;
+    let table = match read_txn.open_table(INCIDENTS_TABLE) {
+        Ok(t) => t,
+        Err(redb::TableError::TableDoesNotExist(_)) => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut incidents = Vec::new();
+    for item in table.iter()?.rev() {
+        let (_, value) = item?;
+        let incident: Incident = serde_json::from_str(value.value())?;
+        if status.is_none_or(|s| incident.status == s) {
+            incidents.push(incident);
+        }
+    }
+    Ok(incidents)
+}
+
+pub fn get(db: &Database, id: u64) -> Result<Incident, KernelError> {
+    let read_txn = db.begin_read()?;
+    let table = match read_txn.open_table(INCIDENTS_TABLE) {
+        Ok(t) => t,
+        Err(redb::TableError::TableDoesNotExist(_)) => return Err(KernelError::not_found("incident", id.to_string())),
+        Err(e) => return Err(e.into()),
+    };
+    read_incident(&table, id)
+}
+
+// Loads an incident, applies `change` and stores it in one write transaction
+fn update(
+    db: &Database,
+    id: u64,
+    change: impl FnOnce(&mut Incident) -> Result<(), KernelError>,
+) -> Result<Incident, KernelError> {
+    let write_txn = db.begin_write()?;
+    let incident = {
+        let mut table = write_txn.open_table(INCIDENTS_TABLE)?;
+        let mut incident = read_incident(&table, id)?;
+        change(&mut incident)?;
+        let json = serde_json::to_string(&incident)?;
+        table.insert(id, json.as_str())?;
+        incident
+    };
+    write_txn.commit()?;
+    Ok(incident)
+}
+
+pub fn add_responder(db: &Database, id: u64, new: NewResponder) -> Result<Incident, KernelError> {
+    let name = new.name.trim().to_string();
+    if name.is_empty() {
+        return Err(KernelError::Validation(vec!["name must not be empty".to_string()]));
+    }
+    update(db, id, |i| {
+        i.require_status(Status::Active)?;
+        if !i.is_leader(&new.added_by) {
+            return Err(KernelError::Forbidden(format!("Only the incident leader ({}) adds responders", i.leader)));
+        }
+        if i.is_responder(&name) {
+            return Err(KernelError::Conflict(format!("{} is already a responder", name)));
+        }
+        i.responders.push(name);
+        Ok(())
+    })
+}
+
+pub fn append(db: &Database, id: u64, new: NewEntry) -> Result<LogEntry, KernelError> {
+    let author = new.author.trim().to_string();
+    let text = new.text.trim().to_string();
+    let mut problems = Vec::new();
+    if author.is_empty() {
+        problems.push("author must not be empty".to_string());
+    }
+    if text.is_empty() {
+        problems.push("text must not be empty".to_string());
+    }
+    if !problems.is_empty() {
+        return Err(KernelError::Validation(problems));
+    }
+
+    let write_txn = db.begin_write()?;
+    let entry = {
+        let incident = read_incident(&write_txn.open_table(INCIDENTS_TABLE)?, id)?;
+        let logged_at = Utc::now();
+        match new.kind {
+            EntryKind::Concern => {
+                if incident.status == Status::Closed {
+                    return Err(KernelError::Conflict(format!("Incident {} is closed", id)));
+                }
+            }
+            EntryKind::Command | EntryKind::Note => {
+                incident.require_status(Status::Active)?;
+                if !incident.is_responder(&author) {
+                    return Err(KernelError::Forbidden(format!(
+                        "{} is not a responder on incident {}; the channel is read-only (raise a concern instead)",
+                        author, id
+                    )));
+                }
+            }
+        }
+        match (new.kind, new.execute_at) {
+            (EntryKind::Command, None) => {
+                return Err(KernelError::Validation(vec!["commands need an execute_at time".to_string()]))
+            }
+            (EntryKind::Command, Some(at)) if at <= logged_at => {
+                return Err(KernelError::Validation(vec![format!(
+                    "commands must be logged before they run; execute_at {} is not after {}",
+                    at.to_rfc3339(),
+                    logged_at.to_rfc3339()
+                )]))
+            }
+            (EntryKind::Note, _) if text.chars().count() > MAX_NOTE_CHARS => {
+                return Err(KernelError::Validation(vec![format!(
+                    "notes are terse: at most {} characters",
+                    MAX_NOTE_CHARS
+                )]))
+            }
+            (EntryKind::Note | EntryKind::Concern, Some(_)) => {
+                return Err(KernelError::Validation(vec!["execute_at only applies to commands".to_string()]))
+            }
+            _ => {}
+        }
+
+        let mut log = write_txn.open_table(LOG_TABLE)?;
+        let mut seq = 0;
+        if let Some(item) = log.range((id, 0)..=(id, u64::MAX))?.next_back() {
+            seq = item?.0.value().1;
+        }
+        let entry = LogEntry {
+            seq: seq + 1,
+            kind: new.kind,
+            author,
+            text,
+            logged_at,
+            execute_at: new.execute_at,
+        };
+        let json = serde_json::to_string(&entry)?;
+        log.insert((id, entry.seq), json.as_str())?;
+        entry
+    };
+    write_txn.commit()?;
+    Ok(entry)
+}
+
+pub fn log(db: &Database, id: u64) -> Result<Vec<LogEntry>, KernelError> {
+    get(db, id)?;
+    let read_txn = db.begin_read()?;
+    let table = read_txn.open_table(LOG_TABLE)?;
+    let mut entries = Vec::new();
+    for item in table.range((id, 0)..=(id, u64::MAX))? {
+        let (_, value) = item?;
+        entries.push(serde_json::from_str(value.value())?);
+    }
+    Ok(entries)
+}
+
+pub fn resolve(db: &Database, id: u64, resolution: Resolution) -> Result<Incident, KernelError> {
+    if resolution.summary.trim().is_empty() {
+        return Err(KernelError::Validation(vec!["summary must not be empty".to_string()]));
+    }
+    update(db, id, |i| {
+        i.require_status(Status::Active)?;
+        if !i.is_leader(&resolution.by) {
+            return Err(KernelError::Forbidden(format!("Only the incident leader ({}) resolves the incident", i.leader)));
+        }
+        i.status = Status::Resolved;
+        i.resolution = Some(resolution.summary.trim().to_string());
+        i.resolved_at = Some(Utc::now());
+        Ok(())
+    })
+}
+
+pub fn record_retrospective(db: &Database, id: u64, new: NewRetrospective) -> Result<Incident, KernelError> {
+    update(db, id, |i| {
+        i.require_status(Status::Resolved)?;
+        if i.retrospective.is_some() {
+            return Err(KernelError::Conflict(format!("Incident {} already has a retrospective", id)));
+        }
+
+        let mut problems = Vec::new();
+        if new.facilitator.trim().is_empty() {
+            problems.push("facilitator must not be empty".to_string());
+        }
+        // Warm: the retrospective is about the people as much as the system
+        if new.check_ins.is_empty() {
+            problems.push("check_ins must record how at least one person is doing".to_string());
+        }
+        if new.check_ins.iter().any(|c| c.name.trim().is_empty() || c.feeling.trim().is_empty()) {
+            problems.push("every check-in needs a name and a feeling".to_string());
+        }
+        if new.learnings.iter().all(|l| l.trim().is_empty()) {
+            problems.push("learnings must not be empty".to_string());
+        }
+        // Semteende: the leader speaks last
+        if let Some(pos) = new.check_ins.iter().position(|c| i.is_leader(&c.name)) {
+            if pos + 1 != new.check_ins.len() {
+                problems.push(format!("the leader ({}) checks in last", i.leader));
+            }
+        }
+        if !problems.is_empty() {
+            return Err(KernelError::Validation(problems));
+        }
+
+        i.retrospective = Some(Retrospective {
+            facilitator: new.facilitator.trim().to_string(),
+            held_at: Utc::now(),
+            check_ins: new.check_ins,
+            learnings: new.learnings.into_iter().filter(|l| !l.trim().is_empty()).collect(),
+            follow_ups: new.follow_ups,
+        });
+        Ok(())
+    })
+}
+
+pub fn close(db: &Database, id: u64) -> Result<Incident, KernelError> {
+    update(db, id, |i| {
+        i.require_status(Status::Resolved)?;
+        if i.retrospective.is_none() {
+            return Err(KernelError::Conflict(format!(
+                "Incident {} needs a warm retrospective before it can be closed",
+                id
+            )));
+        }
+        i.status = Status::Closed;
+        i.closed_at = Some(Utc::now());
+        Ok(())
+    })
+}
+
+// --- CLI: `culture-kernel incidents` ---
+
+#[derive(Subcommand, Debug)]
+pub enum IncidentsCommand {
+    /// Declare a Severity-1 incident
+    Declare {
+        title: String,
+        #[arg(long)]
+        leader: String,
+        /// Responders besides the leader (repeat the flag or separate with commas)
+        #[arg(short, long = "responder", value_delimiter = ',')]
+        responders: Vec<String>,
+    },
+    /// List incidents, newest first
+    List {
+        /// active, resolved or closed
+        #[arg(long)]
+        status: Option<String>,
+    },
+    /// Show an incident with its command log
+    Show { id: u64 },
+    /// Add a responder (leader only)
+    AddResponder {
+        id: u64,
+        name: String,
+        #[arg(long = "by")]
+        added_by: String,
+    },
+    /// Log a command before running it
+    Run {
+        id: u64,
+        #[arg(long = "by")]
+        author: String,
+        /// When it will run (RFC 3339); defaults to 30 seconds from now
+        #[arg(long)]
+        at: Option<DateTime<Utc>>,
+        command: String,
+    },
+    /// Post a short status note (responders only)
+    Note {
+        id: u64,
+        #[arg(long = "by")]
+        author: String,
+        text: String,
+    },
+    /// Raise a safety or ethics concern (anyone)
+    Concern {
+        id: u64,
+        #[arg(long = "by")]
+        author: String,
+        text: String,
+    },
+    /// Mark the incident resolved (leader only)
+    Resolve {
+        id: u64,
+        #[arg(long = "by")]
+        by: String,
+        #[arg(long)]
+        summary: String,
+    },
+    /// Record the warm retrospective
+    Retro {
+        id: u64,
+        #[arg(long)]
+        facilitator: String,
+        /// NAME=FEELING, repeatable; the leader goes last
+        #[arg(long = "check-in", required = true)]
+        check_ins: Vec<CheckIn>,
+        /// Repeatable
+        #[arg(long = "learning", required = true)]
+        learnings: Vec<String>,
+        /// Repeatable
+        #[arg(long = "follow-up")]
+        follow_ups: Vec<String>,
+    },
+    /// Close a resolved incident (needs a retrospective)
+    Close { id: u64 },
+}
+
+fn print_summary(i: &Incident) {
+    let status = match i.status {
+        Status::Active => i.status.as_str().red().bold(),
+        Status::Resolved => i.status.as_str().yellow().bold(),
+        Status::Closed => i.status.as_str().green().bold(),
+    };
+    println!(
+        "#{:<4} {:<8} {} (leader: {}, declared {})",
+        i.id,
+        status,
+        i.title,
+        i.leader.cyan(),
+        i.declared_at.format("%Y-%m-%d %H:%M UTC")
+    );
+}
+
+fn print_entry(e: &LogEntry) {
+    let kind = match e.kind {
+        EntryKind::Command => "CMD".cyan().bold(),
+        EntryKind::Note => "NOTE".normal(),
+        EntryKind::Concern => "CONCERN".magenta().bold(),
+    };
+    let when = e
+        .execute_at
+        .map(|at| format!(" (runs {})", at.format("%H:%M:%S")))
+        .unwrap_or_default();
+    println!("  {:>3} {} {:<7} {}: {}{}", e.seq, e.logged_at.format("%H:%M:%S"), kind, e.author, e.text, when.dimmed());
+}
+
+pub fn incidents_cli(db: &Arc<Database>, command: &IncidentsCommand) -> anyhow::Result<()> {
+    match command {
+        IncidentsCommand::Declare { title, leader, responders } => {
+            let i = declare(
+                db,
+                NewIncident { title: title.clone(), leader: leader.clone(), responders: responders.clone() },
+            )?;
+            println!("{} incident #{}: {}", "Declared".red().bold(), i.id, i.title);
+            println!("Responders: {}. Everyone else is read-only.", i.responders.join(", "));
+        }
+        IncidentsCommand::List { status } => {
+            let incidents = list(db, &IncidentFilter { status: status.clone() })?;
+            if incidents.is_empty() {
+                println!("No incidents.");
+            }
+            for i in &incidents {
+                print_summary(i);
+            }
+        }
+        IncidentsCommand::Show { id } => {
+            let i = get(db, *id)?;
+            print_summary(&i);
+            println!("  responders: {}", i.responders.join(", "));
+            for e in log(db, *id)? {
+                print_entry(&e);
+            }
+            if let Some(resolution) = &i.resolution {
+                println!("  resolution: {}", resolution.green());
+            }
+            if let Some(r) = &i.retrospective {
+                println!("  retrospective facilitated by {}:", r.facilitator);
+                for c in &r.check_ins {
+                    println!("    {} feels {}", c.name, c.feeling.italic());
+                }
+                for l in &r.learnings {
+                    println!("    learned: {}", l);
+                }
+                for f in &r.follow_ups {
+                    println!("    follow-up: {}", f);
+                }
+            }
+        }
+        IncidentsCommand::AddResponder { id, name, added_by } => {
+            add_responder(db, *id, NewResponder { name: name.clone(), added_by: added_by.clone() })?;
+            println!("{} {} to incident #{}", "Added".green().bold(), name.cyan(), id);
+        }
+        IncidentsCommand::Run { id, author, at, command } => {
+            let execute_at = at.unwrap_or_else(|| Utc::now() + Duration::seconds(DEFAULT_LEAD_SECONDS));
+            let entry = append(
+                db,
+                *id,
+                NewEntry { kind: EntryKind::Command, author: author.clone(), text: command.clone(), execute_at: Some(execute_at) },
+            )?;
+            print_entry(&entry);
+        }
+        IncidentsCommand::Note { id, author, text } => {
+            let entry = append(
+                db,
+                *id,
+                NewEntry { kind: EntryKind::Note, author: author.clone(), text: text.clone(), execute_at: None },
+            )?;
+            print_entry(&entry);
+        }
+        IncidentsCommand::Concern { id, author, text } => {
+            let entry = append(
+                db,
+                *id,
+                NewEntry { kind: EntryKind::Concern, author: author.clone(), text: text.clone(), execute_at: None },
+            )?;
+            print_entry(&entry);
+        }
+        IncidentsCommand::Resolve { id, by, summary } => {
+            resolve(db, *id, Resolution { by: by.clone(), summary: summary.clone() })?;
+            println!("{} incident #{}; hold the warm retrospective before closing", "Resolved".yellow().bold(), id);
+        }
+        IncidentsCommand::Retro { id, facilitator, check_ins, learnings, follow_ups } => {
+            record_retrospective(
+                db,
+                *id,
+                NewRetrospective {
+                    facilitator: facilitator.clone(),
+                    check_ins: check_ins.clone(),
+                    learnings: learnings.clone(),
+                    follow_ups: follow_ups.clone(),
+                },
+            )?;
+            println!("{} retrospective for incident #{}", "Recorded".green().bold(), id);
+        }
+        IncidentsCommand::Close { id } => {
+            close(db, *id)?;
+            println!("{} incident #{}", "Closed".green().bold(), id);
+        }
+    }
+    Ok(())
+}
+
+// --- API: /incidents ---
+
+// POST /incidents
+pub async fn api_declare(
+    State(db): State<Arc<Database>>,
+    body: Result<Json<NewIncident>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(new) = body?;
+    let incident = declare(&db, new)?;
+    Ok((StatusCode::CREATED, Json(incident)).into_response())
+}
+
+// GET /incidents?status=
+pub async fn api_list(
+    State(db): State<Arc<Database>>,
+    Query(filter): Query<IncidentFilter>,
+) -> Result<Response, KernelError> {
+    Ok(Json(list(&db, &filter)?).into_response())
+}
+
+// GET /incidents/{id}
+pub async fn api_get(State(db): State<Arc<Database>>, Path(id): Path<u64>) -> Result<Response, KernelError> {
+    Ok(Json(get(&db, id)?).into_response())
+}
+
+// POST /incidents/{id}/responders
+pub async fn api_add_responder(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<u64>,
+    body: Result<Json<NewResponder>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(new) = body?;
+    let incident = add_responder(&db, id, new)?;
+    Ok((StatusCode::CREATED, Json(incident)).into_response())
+}
+
+// GET /incidents/{id}/log
+pub async fn api_log(State(db): State<Arc<Database>>, Path(id): Path<u64>) -> Result<Response, KernelError> {
+    Ok(Json(log(&db, id)?).into_response())
+}
+
+// POST /incidents/{id}/log
+pub async fn api_append(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<u64>,
+    body: Result<Json<NewEntry>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(new) = body?;
+    let entry = append(&db, id, new)?;
+    Ok((StatusCode::CREATED, Json(entry)).into_response())
+}
+
+// POST /incidents/{id}/resolve
+pub async fn api_resolve(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<u64>,
+    body: Result<Json<Resolution>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(resolution) = body?;
+    Ok(Json(resolve(&db, id, resolution)?).into_response())
+}
+
+// POST /incidents/{id}/retrospective
+pub async fn api_retrospective(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<u64>,
+    body: Result<Json<NewRetrospective>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(new) = body?;
+    let incident = record_retrospective(&db, id, new)?;
+    Ok((StatusCode::CREATED, Json(incident)).into_response())
+}
+
+// POST /incidents/{id}/close
+pub async fn api_close(State(db): State<Arc<Database>>, Path(id): Path<u64>) -> Result<Response, KernelError> {
+    Ok(Json(close(&db, id)?).into_response())
+}
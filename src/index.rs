@@ -0,0 +1,206 @@
+// --- SECONDARY INDEXES ---
+// Multimap tables mapping category, origin culture and guardrail keywords to ritual ids,
+// plus the full-text postings in search.rs. Every write to RITUALS_TABLE goes through
+// `put_ritual` / `remove_ritual`, which keep all of them in the same write transaction
+// as the record itself.
+//
+// Lookups match filter needles against the (few, short) index *keys* rather than every
+// stored record, so filtered queries only deserialize candidate rituals.
+
+use redb::{
+    Database, MultimapTableDefinition, ReadableMultimapTable, ReadableTable, WriteTransaction,
+};
+use std::collections::BTreeSet;
+
+use crate::error::KernelError;
+use crate::{read_meta, search, Ritual, RitualFilter, META_TABLE, RITUALS_TABLE};
+
+const CATEGORY_INDEX: MultimapTableDefinition<&str, &str> = MultimapTableDefinition::new("idx_category");
+const ORIGIN_INDEX: MultimapTableDefinition<&str, &str> = MultimapTableDefinition::new("idx_origin");
+const GUARDRAIL_INDEX: MultimapTableDefinition<&str, &str> = MultimapTableDefinition::new("idx_guardrail");
+
+// Bump when the key derivation changes; a mismatch triggers a rebuild at startup
+const INDEX_VERSION: &str = "2";
+const INDEX_VERSION_KEY: &str = "index_version";
+
+// Guardrail keywords: lowercase alphanumeric words of 3+ characters. No stopword list,
+// so any word-ish needle is always a substring of some indexed keyword.
+pub fn keywords(text: &str) -> BTreeSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.chars().count() >= 3)
+        .map(|w| w.to_lowercase())
+        .collect()
+}
+
+fn index_keys(ritual: &Ritual) -> [(MultimapTableDefinition<'static, &'static str, &'static str>, BTreeSet<String>); 3] {
+    let guardrail_words = ritual
+        .ethical_guardrails
+        .iter()
+        .flat_map(|g| keywords(g))
+        .collect();
+
+    [
+        (CATEGORY_INDEX, BTreeSet::from([ritual.category.to_lowercase()])),
+        (ORIGIN_INDEX, BTreeSet::from([ritual.origin_culture.to_lowercase()])),
+        (GUARDRAIL_INDEX, guardrail_words),
+    ]
+}
+
+fn add_to_indexes(txn: &WriteTransaction, ritual: &Ritual) -> Result<(), KernelError> {
+    for (definition, keys) in index_keys(ritual) {
+        let mut table = txn.open_multimap_table(definition)?;
+        for key in keys {
+            table.insert(key.as_str(), ritual.id.as_str())?;
+        }
+    }
+    search::add_document(txn, ritual)
+}
+
+fn remove_from_indexes(txn: &WriteTransaction, ritual: &Ritual) -> Result<(), KernelError> {
+    for (definition, keys) in index_keys(ritual) {
+        let mut table = txn.open_multimap_table(definition)?;
+        for key in keys {
+            table.remove(key.as_str(), ritual.id.as_str())?;
+        }
+    }
+    search::remove_document(txn, ritual)
+}
+
+// The ritual a stored row was indexed as: only rows that parse and whose id matches
+// their key are ever indexed, so only those may be de-indexed
+fn indexed_record(raw: &str, key: &str) -> Option<Ritual> {
+    serde_json::from_str::<Ritual>(raw).ok().filter(|r| r.id == key)
+}
+
+// Writes a ritual and its index entries. Returns the previously stored raw JSON.
+pub fn put_ritual(txn: &WriteTransaction, ritual: &Ritual) -> Result<Option<String>, KernelError> {
+    let previous = {
+        let mut table = txn.open_table(RITUALS_TABLE)?;
+        let json = serde_json::to_string(ritual)?;
+        let previous = table
+            .insert(ritual.id.as_str(), json.as_str())?
+            .map(|v| v.value().to_string());
+        previous
+    };
+
+    if let Some(old) = previous.as_deref().and_then(|raw| indexed_record(raw, &ritual.id)) {
+        remove_from_indexes(txn, &old)?;
+    }
+    add_to_indexes(txn, ritual)?;
+    Ok(previous)
+}
+
+// Removes a ritual and its index entries. Returns the removed raw JSON.
+// Unparsable or misfiled records were never indexed by the kernel, so only the row goes.
+pub fn remove_ritual(txn: &WriteTransaction, id: &str) -> Result<Option<String>, KernelError> {
+    let removed = {
+        let mut table = txn.open_table(RITUALS_TABLE)?;
+        let removed = table.remove(id)?.map(|v| v.value().to_string());
+        removed
+    };
+
+    if let Some(old) = removed.as_deref().and_then(|raw| indexed_record(raw, id)) {
+        remove_from_indexes(txn, &old)?;
+    }
+    Ok(removed)
+}
+
+// Drops and repopulates every index from RITUALS_TABLE
+pub fn rebuild(db: &Database) -> Result<usize, KernelError> {
+    let write_txn = db.begin_write()?;
+    let mut indexed = 0;
+    {
+        for definition in [CATEGORY_INDEX, ORIGIN_INDEX, GUARDRAIL_INDEX] {
+            write_txn.delete_multimap_table(definition)?;
+        }
+        search::clear(&write_txn)?;
+
+        let rituals: Vec<Ritual> = {
+            let table = write_txn.open_table(RITUALS_TABLE)?;
+            let mut rituals = Vec::new();
+            for item in table.iter()? {
+                let (key, value) = item?;
+                // Corrupt rows are left for the quarantine; they just are not indexed
+                if let Some(ritual) = indexed_record(value.value(), key.value()) {
+                    rituals.push(ritual);
+                }
+            }
+            rituals
+        };
+
+        for ritual in &rituals {
+            add_to_indexes(&write_txn, ritual)?;
+            indexed += 1;
+        }
+
+        let mut meta = write_txn.open_table(META_TABLE)?;
+        meta.insert(INDEX_VERSION_KEY, INDEX_VERSION)?;
+    }
+    write_txn.commit()?;
+    Ok(indexed)
+}
+
+// Rebuilds the indexes if they predate this kernel (or were never built)
+pub fn ensure_current(db: &Database) -> Result<(), KernelError> {
+    if read_meta(db, INDEX_VERSION_KEY)?.as_deref() != Some(INDEX_VERSION) {
+        rebuild(db)?;
+    }
+    Ok(())
+}
+
+// Ids that may match the indexed parts of the filter, or None when no indexed
+// filter applies (the caller then falls back to a full scan). Always a superset:
+// callers must still run `RitualFilter::matches` on each candidate.
+pub fn candidates(db: &Database, filter: &RitualFilter) -> Result<Option<BTreeSet<String>>, KernelError> {
+    let read_txn = db.begin_read()?;
+    let mut result: Option<BTreeSet<String>> = None;
+
+    let mut constrain = |ids: BTreeSet<String>| {
+        result = Some(match result.take() {
+            Some(current) => current.intersection(&ids).cloned().collect(),
+            None => ids,
+        });
+    };
+
+    if let Some(category) = non_empty(&filter.category) {
+        constrain(ids_for_keys_containing(&read_txn, CATEGORY_INDEX, &category.to_lowercase())?);
+    }
+    if let Some(origin) = non_empty(&filter.origin) {
+        constrain(ids_for_keys_containing(&read_txn, ORIGIN_INDEX, &origin.to_lowercase())?);
+    }
+    if let Some(guardrail) = non_empty(&filter.guardrail) {
+        // Every word in the needle must appear inside some keyword of the ritual
+        for word in keywords(guardrail) {
+            constrain(ids_for_keys_containing(&read_txn, GUARDRAIL_INDEX, &word)?);
+        }
+    }
+
+    Ok(result)
+}
+
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value.as_deref().filter(|v| !v.is_empty())
+}
+
+fn ids_for_keys_containing(
+    read_txn: &redb::ReadTransaction,
+    definition: MultimapTableDefinition<&str, &str>,
+    needle: &str,
+) -> Result<BTreeSet<String>, KernelError> {
+    let table = match read_txn.open_multimap_table(definition) {
+        Ok(t) => t,
+        Err(redb::TableError::TableDoesNotExist(_)) => return Ok(BTreeSet::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut ids = BTreeSet::new();
+    for item in table.iter()? {
+        let (key, values) = item?;
+        if key.value().contains(needle) {
+            for id in values {
+                ids.insert(id?.value().to_string());
+            }
+        }
+    }
+    Ok(ids)
+}
@@ -1,8 +1,9 @@
 use axum::{
-    extract::{State, Request},
+    extract::{rejection::JsonRejection, Path, Query, State},
+    http::{HeaderMap, StatusCode},
     response::{IntoResponse, Response},
-    routing::get,
-    Router, Json,
+    routing::{delete, get, patch, post, put},
+    Extension, Router, Json,
 };
 use clap::{Parser, Subcommand};
 use redb::{Database, ReadableTable, TableDefinition};
@@ -11,11 +12,36 @@ use std::sync::Arc;
 use colored::*; // For ANSI colors
 use tower_http::cors::CorsLayer;
 use std::collections::HashMap; 
+use std::path::PathBuf;
+
+mod assessment;
+mod calendar;
+mod diagnose;
+mod disputes;
+mod error;
+mod esusu;
+mod guild;
+mod heal;
+mod igba_boi;
+mod incidents;
+mod index;
+mod quarantine;
+mod recurrence;
+mod render;
+mod schedule;
+mod schema;
+mod search;
+mod seed;
+mod sessions;
+mod teams;
+use error::KernelError;
+use render::FormatParam;
 
 // --- 1. DATA MODELS  ---
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct Ritual {
-    // Matches JSON "id": "IGBO_01..."
+    // Matches JSON "id": "IGBO_01..." (v1 records and the first README used "protocol_id")
+    #[serde(alias = "protocol_id")]
     id: String, 
     
     // Matches JSON "name": "Venture..."
@@ -39,45 +65,315 @@ struct Ritual {
     
     // Matches JSON "ethical_guardrails": ["...", "..."]
     ethical_guardrails: Vec<String>,
+
+    // Matches JSON "schedule": { "rrule": "FREQ=MONTHLY;BYDAY=-1FR", "start": "...", ... }
+    // Optional: rituals triggered by events (a hire, an outage) have no calendar
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    schedule: Option<schedule::Schedule>,
+}
+
+impl Ritual {
+    // Returns every problem found, so curators can fix a submission in one round-trip
+    fn validate(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        if self.id.is_empty() {
+            problems.push("id must not be empty".to_string());
+        } else if !self.id.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_') {
+            problems.push("id may only contain A-Z, 0-9 and '_' (e.g. RWANDA_19_IMIHIGO)".to_string());
+        }
+
+        let required = [
+            ("name", &self.name),
+            ("origin_culture", &self.origin_culture),
+            ("category", &self.category),
+            ("bug_fixed", &self.bug_fixed),
+            ("mechanism", &self.mechanism),
+        ];
+        for (field, value) in required {
+            if value.trim().is_empty() {
+                problems.push(format!("{} must not be empty", field));
+            }
+        }
+
+        if self.modern_script.is_empty() {
+            problems.push("modern_script must contain at least one step".to_string());
+        }
+        if self.modern_script.iter().any(|(k, v)| k.trim().is_empty() || v.trim().is_empty()) {
+            problems.push("modern_script keys and values must not be empty".to_string());
+        }
+
+        if self.ethical_guardrails.is_empty() {
+            problems.push("ethical_guardrails must contain at least one guardrail".to_string());
+        }
+        if self.ethical_guardrails.iter().any(|g| g.trim().is_empty()) {
+            problems.push("ethical_guardrails must not contain empty entries".to_string());
+        }
+
+        if let Some(schedule) = &self.schedule {
+            problems.extend(schedule.validate());
+        }
+
+        problems
+    }
 }
 
 const RITUALS_TABLE: TableDefinition<&str, &str> = TableDefinition::new("rituals");
+// Kernel-wide settings such as the stored schema version
+const META_TABLE: TableDefinition<&str, &str> = TableDefinition::new("meta");
+
+// Query parameters for GET /rituals (e.g. ?category=Crisis%20Management&origin=Hausa&q=incident&guardrail=salary)
+// Every filter is a case-insensitive substring match; all supplied filters must match.
+#[derive(Debug, Deserialize, Default)]
+struct RitualFilter {
+    category: Option<String>,
+    origin: Option<String>,
+    guardrail: Option<String>,
+    q: Option<String>,
+}
+
+impl RitualFilter {
+    fn matches(&self, ritual: &Ritual) -> bool {
+        fn contains(haystack: &str, needle: &str) -> bool {
+            haystack.to_lowercase().contains(&needle.to_lowercase())
+        }
+
+        if let Some(category) = self.category.as_deref().filter(|c| !c.is_empty()) {
+            if !contains(&ritual.category, category) {
+                return false;
+            }
+        }
+
+        if let Some(origin) = self.origin.as_deref().filter(|o| !o.is_empty()) {
+            if !contains(&ritual.origin_culture, origin) {
+                return false;
+            }
+        }
+
+        if let Some(guardrail) = self.guardrail.as_deref().filter(|g| !g.is_empty()) {
+            if !ritual.ethical_guardrails.iter().any(|g| contains(g, guardrail)) {
+                return false;
+            }
+        }
+
+        if let Some(q) = self.q.as_deref().filter(|q| !q.is_empty()) {
+            let hit = contains(&ritual.name, q)
+                || contains(&ritual.category, q)
+                || contains(&ritual.origin_culture, q)
+                || contains(&ritual.bug_fixed, q)
+                || contains(&ritual.mechanism, q)
+                || ritual.modern_script.values().any(|v| contains(v, q));
+            if !hit {
+                return false;
+            }
+        }
+
+        true
+    }
+}
 
 // --- 2. CLI STRUCTURE ---
 #[derive(Parser)]
 #[command(name = "Culture Kernel")]
 struct Cli {
+    /// Path of the redb database file
+    #[arg(long, global = true, env = "CULTURE_KERNEL_DB", default_value = "culture.redb")]
+    db: PathBuf,
+
+    /// Seed file or pack directory; repeat (or comma-separate) to layer several, later
+    /// ones win. Defaults to ./rituals.json if present, else the embedded library.
+    #[arg(long = "seed-file", global = true, env = "CULTURE_KERNEL_SEED", value_delimiter = ',')]
+    seed_files: Vec<PathBuf>,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
 
 #[derive(Subcommand)]
 enum Commands {
+    /// Start the HTTP API (auto-seeds an empty database)
     Serve {
         #[arg(short, long, default_value = "8080")]
         port: u16,
+        /// Startup integrity pass against the seed manifest
+        #[arg(long, value_enum, env = "CULTURE_KERNEL_HEAL", default_value_t = heal::HealMode::Repair)]
+        heal: heal::HealMode,
     },
+    /// List every stored ritual
     List,
-    Seed,
+    /// Load the seed file(s) into the database
+    Seed {
+        /// How to reconcile the seed with rituals already stored
+        #[arg(long, value_enum, default_value_t = seed::SeedMode::Upsert)]
+        mode: seed::SeedMode,
+        /// Print the added/changed/removed diff without writing anything
+        #[arg(long)]
+        dry_run: bool,
+        /// Re-apply even if the seed is unchanged since the last run
+        #[arg(long)]
+        force: bool,
+    },
+    /// Scan for corrupt rituals and manage the quarantine
+    Doctor {
+        /// Retry parsing every quarantined record and restore those that now load
+        #[arg(long, conflicts_with = "purge")]
+        restore: bool,
+        /// Permanently delete every quarantined record
+        #[arg(long)]
+        purge: bool,
+    },
+    /// Write the ritual library compiled into this binary to a file (or stdout)
+    ExportDefault {
+        /// Destination file; prints to stdout when omitted
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Overwrite the destination if it exists
+        #[arg(long)]
+        force: bool,
+    },
+    /// Full-text search over the stored rituals, ranked by relevance
+    Search {
+        /// Words to look for, e.g. "blame incident"
+        #[arg(required = true)]
+        query: Vec<String>,
+        /// Maximum number of results
+        #[arg(short, long)]
+        limit: Option<usize>,
+    },
+    /// Answer the culture questionnaire and get a ritual adoption plan
+    Assess {
+        /// Team the answers are stored under
+        #[arg(long, default_value = assessment::DEFAULT_TEAM)]
+        team: String,
+        /// Show this team's stored assessments instead of taking a new one
+        #[arg(long)]
+        history: bool,
+        /// Score the answers without storing them
+        #[arg(long)]
+        no_save: bool,
+    },
+    /// Manage teams and the rituals each of them has adopted
+    Teams {
+        #[command(subcommand)]
+        command: teams::TeamsCommand,
+    },
+    /// Log and review performances of rituals
+    Sessions {
+        #[command(subcommand)]
+        command: sessions::SessionsCommand,
+    },
+    /// Export rituals as iCalendar feeds
+    Calendar {
+        #[command(subcommand)]
+        command: calendar::CalendarCommand,
+    },
+    /// Run Esusu rotating pools between teams
+    Esusu {
+        #[command(subcommand)]
+        command: esusu::EsusuCommand,
+    },
+    /// Elect the Sarkin Code and look up who holds the office
+    Guild {
+        #[command(subcommand)]
+        command: guild::GuildCommand,
+    },
+    /// File and resolve API contract disputes before the guild
+    Disputes {
+        #[command(subcommand)]
+        command: disputes::DisputesCommand,
+    },
+    /// Run Severity-1 incidents under the Pulaaku rules
+    Incidents {
+        #[command(subcommand)]
+        command: incidents::IncidentsCommand,
+    },
+    /// Simulate the Igba Boi settlement escrow month by month
+    IgbaBoi(igba_boi::IgbaBoiArgs),
+    /// List upcoming occurrences of scheduled rituals
+    Schedule {
+        /// How many days ahead to look (1-366)
+        #[arg(long)]
+        days: Option<i64>,
+        /// Only this ritual, e.g. RWANDA_21_UMUGANDA
+        #[arg(long)]
+        ritual: Option<String>,
+    },
+    /// Render one ritual as a Markdown document in the terminal
+    Show {
+        /// Ritual id, e.g. RWANDA_19_IMIHIGO
+        id: String,
+        /// Print the Markdown source instead of rendering it
+        #[arg(long)]
+        raw: bool,
+    },
 }
 
 // --- 3. MAIN KERNEL LOOP ---
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
-    let db = Database::create("culture.redb")?;
+    let db = Database::create(&cli.db)
+        .map_err(|e| anyhow::anyhow!("cannot open database '{}': {}", cli.db.display(), e))?;
     let db_arc = Arc::new(db);
 
+    // Upgrade records written by older kernels before anything reads them
+    if schema::migrate(&db_arc)?.migrated > 0 {
+        index::rebuild(&db_arc)?;
+    }
+    index::ensure_current(&db_arc)?;
+
     match &cli.command {
-        Some(Commands::Seed) => {
-            seed_database(&db_arc)?;
-            println!("{}", "Database seeded successfully.".green().bold());
+        Some(Commands::Seed { mode, dry_run, force }) => {
+            let outcome = seed::run(&db_arc, &cli.seed_files, *mode, *dry_run, *force)?;
+            seed::print_outcome(&outcome, *mode);
         }
         Some(Commands::List) => {
             list_rituals_cli(&db_arc)?;
         }
-        Some(Commands::Serve { port }) => {
-            start_server(db_arc, *port).await?;
+        Some(Commands::Doctor { restore, purge }) => {
+            quarantine::doctor_cli(&db_arc, *restore, *purge)?;
+        }
+        Some(Commands::ExportDefault { output, force }) => {
+            seed::export_default_cli(output.as_deref(), *force)?;
+        }
+        Some(Commands::Show { id, raw }) => {
+            show_ritual_cli(&db_arc, id, *raw)?;
+        }
+        Some(Commands::Assess { team, history, no_save }) => {
+            assessment::assess_cli(&db_arc, team, *history, !*no_save)?;
+        }
+        Some(Commands::Teams { command }) => {
+            teams::teams_cli(&db_arc, command)?;
+        }
+        Some(Commands::Sessions { command }) => {
+            sessions::sessions_cli(&db_arc, command)?;
+        }
+        Some(Commands::Calendar { command }) => {
+            calendar::calendar_cli(&db_arc, command)?;
+        }
+        Some(Commands::Esusu { command }) => {
+            esusu::esusu_cli(&db_arc, command)?;
+        }
+        Some(Commands::Guild { command }) => {
+            guild::guild_cli(&db_arc, command)?;
+        }
+        Some(Commands::Disputes { command }) => {
+            disputes::disputes_cli(&db_arc, command)?;
+        }
+        Some(Commands::Incidents { command }) => {
+            incidents::incidents_cli(&db_arc, command)?;
+        }
+        Some(Commands::IgbaBoi(args)) => {
+            igba_boi::igba_boi_cli(args)?;
+        }
+        Some(Commands::Schedule { days, ritual }) => {
+            schedule::schedule_cli(&db_arc, *days, ritual.as_deref())?;
+        }
+        Some(Commands::Search { query, limit }) => {
+            search::search_cli(&db_arc, &query.join(" "), *limit)?;
+        }
+        Some(Commands::Serve { port, heal }) => {
+            start_server(db_arc, &cli.seed_files, *port, *heal).await?;
         }
         None => {
             println!("{}", "Culture Kernel v2.2".yellow().bold());
@@ -88,49 +384,177 @@ async fn main() -> anyhow::Result<()> {
 }
 
 // --- 4. DATABASE LOGIC ---
-fn seed_database(db: &Arc<Database>) -> anyhow::Result<()> {
+fn read_meta(db: &Database, key: &str) -> Result<Option<String>, KernelError> {
+    let read_txn = db.begin_read()?;
+    let table = match read_txn.open_table(META_TABLE) {
+        Ok(t) => t,
+        Err(redb::TableError::TableDoesNotExist(_)) => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let value = table.get(key)?.map(|v| v.value().to_string());
+    Ok(value)
+}
+
+// Rituals that pass the filter. Indexed filters (category, origin, guardrail) look up their
+// candidate ids in the secondary indexes and only those rows are read; without one
+// RITUALS_TABLE is scanned in full. Unparsable records are moved to quarantine
+// instead of silently skipped.
+fn load_rituals(db: &Database, filter: &RitualFilter) -> Result<Vec<Ritual>, KernelError> {
+    let candidates = index::candidates(db, filter)?;
+
+    let mut rituals: Vec<Ritual> = Vec::new();
+    let mut corrupt = Vec::new();
+    {
+        let read_txn = db.begin_read()?;
+
+        // Gracefully handle table not existing yet
+        let table = match read_txn.open_table(RITUALS_TABLE) {
+            Ok(t) => t,
+            Err(redb::TableError::TableDoesNotExist(_)) => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut check = |key: &str, raw: &str| match quarantine::parse_stored(key, raw) {
+            Ok(ritual) => {
+                if filter.matches(&ritual) {
+                    rituals.push(ritual);
+                }
+            }
+            Err(error) => corrupt.push(quarantine::CorruptRecord {
+                id: key.to_string(),
+                raw: raw.to_string(),
+                error,
+            }),
+        };
+
+        match candidates {
+            Some(ids) => {
+                for id in ids {
+                    if let Some(value) = table.get(id.as_str())? {
+                        check(&id, value.value());
+                    }
+                }
+            }
+            None => {
+                for item in table.iter()? {
+                    let (key, value) = item?;
+                    check(key.value(), value.value());
+                }
+            }
+        }
+    }
+
+    quarantine::quarantine(db, corrupt)?;
+    Ok(rituals)
+}
+
+fn load_ritual(db: &Database, id: &str) -> Result<Option<Ritual>, KernelError> {
+    let raw = {
+        let read_txn = db.begin_read()?;
+        let table = match read_txn.open_table(RITUALS_TABLE) {
+            Ok(t) => t,
+            Err(redb::TableError::TableDoesNotExist(_)) => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let raw = table.get(id)?.map(|v| v.value().to_string());
+        raw
+    };
+
+    let Some(raw) = raw else {
+        return Ok(None);
+    };
+
+    match quarantine::parse_stored(id, &raw) {
+        Ok(ritual) => Ok(Some(ritual)),
+        Err(error) => {
+            quarantine::quarantine(db, vec![quarantine::CorruptRecord { id: id.to_string(), raw, error }])?;
+            Ok(None)
+        }
+    }
+}
+
+// Inserts or overwrites a ritual. Returns true if the id did not exist before.
+fn store_ritual(db: &Database, ritual: &Ritual) -> Result<bool, KernelError> {
+    let write_txn = db.begin_write()?;
+    let previous = index::put_ritual(&write_txn, ritual)?;
+    write_txn.commit()?;
+    Ok(previous.is_none())
+}
+
+// Inserts a ritual only if the id is free.
+fn create_ritual(db: &Database, ritual: &Ritual) -> Result<(), KernelError> {
     let write_txn = db.begin_write()?;
     {
-        let mut table = write_txn.open_table(RITUALS_TABLE)?;
-        
-        let data = std::fs::read_to_string("rituals.json")?;
-        let rituals: Vec<Ritual> = serde_json::from_str(&data)?;
-        
-        for ritual in rituals {
-            let json = serde_json::to_string(&ritual)?;
-            table.insert(ritual.id.as_str(), json.as_str())?;
+        let table = write_txn.open_table(RITUALS_TABLE)?;
+        if table.get(ritual.id.as_str())?.is_some() {
+            return Err(KernelError::Conflict(format!(
+                "A ritual with id '{}' already exists",
+                ritual.id
+            )));
         }
     }
+    index::put_ritual(&write_txn, ritual)?;
     write_txn.commit()?;
     Ok(())
 }
 
+// Removes a ritual and returns the raw stored JSON, if it existed.
+fn delete_ritual(db: &Database, id: &str) -> Result<Option<String>, KernelError> {
+    let write_txn = db.begin_write()?;
+    let removed = index::remove_ritual(&write_txn, id)?;
+    write_txn.commit()?;
+    Ok(removed)
+}
+
 // Logic for local CLI listing
 fn list_rituals_cli(db: &Arc<Database>) -> anyhow::Result<()> {
-    let read_txn = db.begin_read()?;
-    let table = read_txn.open_table(RITUALS_TABLE)?;
-    
+    let rituals = load_rituals(db, &RitualFilter::default())?;
+    let stats = sessions::listing_stats(db);
+
     println!("{}", " AVAILABLE RITUALS ".on_blue().white().bold());
-    for item in table.iter()? {
-        let (id, value) = item?;
-        let ritual: Ritual = serde_json::from_str(value.value())?;
-        println!("{} - {} ({})", id.value().cyan(), ritual.name, ritual.origin_culture.yellow());
+    for ritual in rituals {
+        println!(
+            "{} - {} ({}) {}",
+            ritual.id.cyan(),
+            ritual.name,
+            ritual.origin_culture.yellow(),
+            format!("[{}]", sessions::describe(stats.get(&ritual.id))).dimmed()
+        );
     }
     Ok(())
 }
 
-// --- 5. API SERVER LOGIC ---
-async fn start_server(db: Arc<Database>, port: u16) -> anyhow::Result<()> {
-    // Self-Healing: Seed if empty
-    let needs_seeding = {
-        let read_txn = db.begin_read()?;
-        read_txn.open_table(RITUALS_TABLE).is_err()
+// Logic for `show`: one ritual as a termimad-rendered document
+fn show_ritual_cli(db: &Arc<Database>, id: &str, raw: bool) -> anyhow::Result<()> {
+    let Some(ritual) = load_ritual(db, id)? else {
+        anyhow::bail!("No ritual with id '{}'. Run 'culture-kernel list' to see them all.", id);
     };
 
-    if needs_seeding {
-        println!("{}", "Auto-seeding kernel...".yellow());
-        seed_database(&db)?;
+    let markdown = render::markdown_ritual(&ritual);
+    if raw {
+        print!("{}", markdown);
+    } else {
+        termimad::print_text(&markdown);
     }
+    Ok(())
+}
+
+// --- 5. API SERVER LOGIC ---
+async fn start_server(
+    db: Arc<Database>,
+    seed_files: &[PathBuf],
+    port: u16,
+    heal_mode: heal::HealMode,
+) -> anyhow::Result<()> {
+    // Self-Healing: verify the library against the seed manifest (and repair it) before serving
+    heal::run(&db, seed_files, heal_mode)?;
+
+    // Responses are coloured for the client's terminal, not ours: without this,
+    // `colored` drops ANSI codes whenever the server's stdout is not a TTY (e.g. Docker).
+    colored::control::set_override(true);
+
+    // Recomputes upcoming ritual occurrences in the background for GET /schedule
+    let schedule_state = schedule::spawn(db.clone());
 
     // --- CORS ---
     let cors = CorsLayer::new()
@@ -138,8 +562,73 @@ async fn start_server(db: Arc<Database>, port: u16) -> anyhow::Result<()> {
         .allow_methods(tower_http::cors::Any);
 
     let app = Router::new()
-        .route("/rituals", get(api_handle_rituals))
+        .route("/rituals", get(api_handle_rituals).post(api_create_ritual))
+        .route(
+            "/rituals/:id",
+            get(api_handle_ritual)
+                .put(api_replace_ritual)
+                .patch(api_patch_ritual)
+                .delete(api_delete_ritual),
+        )
+        .route("/search", get(search::api_search))
+        .route("/diagnose", post(diagnose::api_diagnose))
+        .route(
+            "/assessment",
+            get(assessment::api_questionnaire).post(assessment::api_submit),
+        )
+        .route("/assessment/results", get(assessment::api_history))
+        .route("/teams", get(teams::api_list_teams).post(teams::api_create_team))
+        .route("/teams/:team", get(teams::api_get_team).delete(teams::api_delete_team))
+        .route("/teams/:team/rituals", get(teams::api_team_rituals).post(teams::api_adopt))
+        .route(
+            "/teams/:team/rituals/:id",
+            patch(teams::api_update_adoption).delete(teams::api_remove_adoption),
+        )
+        .route("/adoptions", get(teams::api_adoptions))
+        .route("/teams/:team/sessions", get(sessions::api_for_team))
+        .route("/rituals/:id/sessions", get(sessions::api_for_ritual))
+        .route("/sessions", get(sessions::api_list).post(sessions::api_log))
+        .route("/sessions/:id", get(sessions::api_get).delete(sessions::api_remove))
+        .route("/schedule", get(schedule::api_schedule))
+        .route("/rituals/:id/calendar.ics", get(calendar::api_ritual_feed))
+        .route("/calendar.ics", get(calendar::api_feed))
+        .route("/rituals/:id/simulate", post(igba_boi::api_simulate))
+        .route("/esusu", get(esusu::api_list).post(esusu::api_create))
+        .route("/esusu/:pool", get(esusu::api_status).delete(esusu::api_delete))
+        .route("/esusu/:pool/balances", get(esusu::api_balances))
+        .route(
+            "/esusu/:pool/contributions",
+            get(esusu::api_contributions).post(esusu::api_contribute),
+        )
+        .route("/esusu/:pool/rounds", get(esusu::api_rounds).post(esusu::api_close_round))
+        .route("/guild/sarkin", get(guild::api_sarkin))
+        .route("/guild/elections", get(guild::api_list).post(guild::api_open))
+        .route("/guild/elections/:id", get(guild::api_get))
+        .route("/guild/elections/:id/nominations", post(guild::api_nominate))
+        .route("/guild/elections/:id/voting", post(guild::api_start_voting))
+        .route("/guild/elections/:id/ballots", get(guild::api_ballots).post(guild::api_cast))
+        .route("/guild/elections/:id/close", post(guild::api_close))
+        .route("/guild/disputes", get(disputes::api_list).post(disputes::api_file))
+        .route("/guild/disputes/:id", get(disputes::api_get))
+        .route("/guild/disputes/:id/arbiter", put(disputes::api_assign))
+        .route("/guild/disputes/:id/hearings", post(disputes::api_hear))
+        .route("/guild/disputes/:id/rulings", post(disputes::api_rule))
+        .route("/guild/disputes/:id/appeals", post(disputes::api_appeal))
+        .route("/incidents", get(incidents::api_list).post(incidents::api_declare))
+        .route("/incidents/:id", get(incidents::api_get))
+        .route("/incidents/:id/responders", post(incidents::api_add_responder))
+        .route("/incidents/:id/log", get(incidents::api_log).post(incidents::api_append))
+        .route("/incidents/:id/resolve", post(incidents::api_resolve))
+        .route("/incidents/:id/retrospective", post(incidents::api_retrospective))
+        .route("/incidents/:id/close", post(incidents::api_close))
+        .route(
+            "/admin/quarantine",
+            get(quarantine::api_list).delete(quarantine::api_purge_all),
+        )
+        .route("/admin/quarantine/:id", delete(quarantine::api_purge_one))
+        .route("/admin/quarantine/:id/restore", post(quarantine::api_restore))
         .with_state(db)
+        .layer(Extension(schedule_state))
         .layer(cors);
 
     println!("{} on port {}", "KERNEL LIVE".green().bold(), port);
@@ -148,63 +637,147 @@ async fn start_server(db: Arc<Database>, port: u16) -> anyhow::Result<()> {
     Ok(())
 }
 
-// THE DUAL-MODE HANDLER
+// THE POLYMORPHIC HANDLER (JSON, ANSI, plain text, HTML or Markdown)
 async fn api_handle_rituals(
-    State(db): State<Arc<Database>>, 
-    req: Request
+    State(db): State<Arc<Database>>,
+    Query(filter): Query<RitualFilter>,
+    Query(params): Query<FormatParam>,
+    headers: HeaderMap,
 ) -> Response {
-    // 1. Fetch Data
-    let read_txn = db.begin_read().unwrap();
-    
-    // Gracefully handle table not existing yet
-    let table = match read_txn.open_table(RITUALS_TABLE) {
-        Ok(t) => t,
-        Err(_) => return Json(Vec::<Ritual>::new()).into_response(), 
+    let format = match render::negotiate(&headers, params.format.as_deref()) {
+        Ok(f) => f,
+        Err(e) => return e.into_response(),
     };
-    
-    let mut rituals: Vec<Ritual> = Vec::new();
-    
-    for item in table.iter().unwrap() {
-        let (_, value) = item.unwrap();
-        // If data is corrupt/old schema, skip it instead of crashing
-        if let Ok(ritual) = serde_json::from_str(value.value()) {
-            rituals.push(ritual);
-        }
-    }
-
-    // 2. Detect User-Agent (Is it Curl?)
-    let user_agent = req.headers()
-        .get("user-agent")
-        .and_then(|h| h.to_str().ok())
-        .unwrap_or("unknown");
-
-    let is_terminal = user_agent.to_lowercase().contains("curl") 
-                   || user_agent.to_lowercase().contains("wget");
-
-    // 3. Return the correct format
-    if is_terminal {
-        // Render ANSI Art Table (Updated for new Schema)
-        let mut output = String::new();
-        output.push_str(&format!("{}\n", "╔════════════════════════════════════════════════╗".bright_cyan()));
-        output.push_str(&format!("║  {}  ║\n", "CULTURE KERNEL :: ACTIVE RITUALS".yellow().bold()));
-        output.push_str(&format!("{}\n\n", "╚════════════════════════════════════════════════╝".bright_cyan()));
-
-        for r in rituals {
-            output.push_str(&format!("> {}\n", r.name.green().bold()));
-            output.push_str(&format!("  ID:      {}\n", r.id.cyan()));
-            output.push_str(&format!("  ORIGIN:  {}\n", r.origin_culture));
-            output.push_str(&format!("  BUG FIX: {}\n", r.bug_fixed.italic()));
-            
-            // Loop through the modern_script hashmap
-            output.push_str("  SCRIPT:\n");
-            for (key, val) in r.modern_script {
-                output.push_str(&format!("    - {}: {}\n", key.to_uppercase(), val));
-            }
-            output.push_str("\n──────────────────────────────────────────────────\n\n");
-        }
-        return output.into_response();
+
+    match load_rituals(&db, &filter) {
+        Ok(rituals) => render::rituals_response(format, rituals, || sessions::listing_stats(&db)),
+        Err(e) => e.respond(format),
+    }
+}
+
+// SINGLE-RITUAL LOOKUP (direct redb key access, no table scan)
+async fn api_handle_ritual(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<String>,
+    Query(params): Query<FormatParam>,
+    headers: HeaderMap,
+) -> Response {
+    let format = match render::negotiate(&headers, params.format.as_deref()) {
+        Ok(f) => f,
+        Err(e) => return e.into_response(),
+    };
+
+    match load_ritual(&db, &id) {
+        Ok(Some(ritual)) => render::ritual_response(format, ritual, || sessions::stats_for(&db, &id).ok().flatten()),
+        Ok(None) => KernelError::not_found("ritual", id).respond(format),
+        Err(e) => e.respond(format),
+    }
+}
+
+// --- 6. WRITE API (curation without redeploying) ---
+
+// POST /rituals: create a new ritual, 409 if the id is taken
+async fn api_create_ritual(
+    State(db): State<Arc<Database>>,
+    body: Result<Json<Ritual>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(ritual) = body?;
+    ensure_valid(&ritual)?;
+
+    create_ritual(&db, &ritual)?;
+    Ok((StatusCode::CREATED, Json(ritual)).into_response())
+}
+
+// PUT /rituals/{id}: full replacement (or creation) of a ritual
+async fn api_replace_ritual(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<String>,
+    body: Result<Json<Ritual>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(ritual) = body?;
+    if ritual.id != id {
+        return Err(KernelError::Validation(vec![format!(
+            "body id '{}' does not match path id '{}'",
+            ritual.id, id
+        )]));
+    }
+    ensure_valid(&ritual)?;
+
+    if store_ritual(&db, &ritual)? {
+        Ok((StatusCode::CREATED, Json(ritual)).into_response())
+    } else {
+        Ok(Json(ritual).into_response())
+    }
+}
+
+// PATCH /rituals/{id}: JSON Merge Patch (RFC 7396) over the stored record
+async fn api_patch_ritual(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<String>,
+    body: Result<Json<serde_json::Value>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(patch) = body?;
+    if !patch.is_object() {
+        return Err(KernelError::Validation(vec!["patch body must be a JSON object".to_string()]));
     }
 
-    // Default: Return Full Rich JSON for Frontend
-    Json(rituals).into_response()
+    let existing = load_ritual(&db, &id)?.ok_or_else(|| KernelError::not_found("ritual", &id))?;
+
+    let mut merged = serde_json::to_value(&existing)?;
+    merge_patch(&mut merged, &patch);
+
+    let ritual: Ritual = serde_json::from_value(merged)
+        .map_err(|e| KernelError::Validation(vec![e.to_string()]))?;
+
+    if ritual.id != id {
+        return Err(KernelError::Validation(vec!["id cannot be changed with PATCH".to_string()]));
+    }
+    ensure_valid(&ritual)?;
+
+    store_ritual(&db, &ritual)?;
+    Ok(Json(ritual).into_response())
+}
+
+// DELETE /rituals/{id}: returns the record that was removed
+async fn api_delete_ritual(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<String>,
+) -> Result<Response, KernelError> {
+    let raw = delete_ritual(&db, &id)?.ok_or_else(|| KernelError::not_found("ritual", &id))?;
+
+    match serde_json::from_str::<Ritual>(&raw) {
+        Ok(ritual) => Ok(Json(ritual).into_response()),
+        // Still deleted; hand back whatever was stored
+        Err(_) => Ok(Json(serde_json::json!({ "id": id, "raw": raw })).into_response()),
+    }
+}
+
+fn ensure_valid(ritual: &Ritual) -> Result<(), KernelError> {
+    let problems = ritual.validate();
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(KernelError::Validation(problems))
+    }
+}
+
+// RFC 7396: objects merge recursively, null removes a key, anything else replaces
+fn merge_patch(target: &mut serde_json::Value, patch: &serde_json::Value) {
+    let Some(patch_map) = patch.as_object() else {
+        *target = patch.clone();
+        return;
+    };
+
+    if !target.is_object() {
+        *target = serde_json::Value::Object(serde_json::Map::new());
+    }
+    let target_map = target.as_object_mut().unwrap();
+
+    for (key, value) in patch_map {
+        if value.is_null() {
+            target_map.remove(key);
+        } else {
+            merge_patch(target_map.entry(key.as_str()).or_insert(serde_json::Value::Null), value);
+        }
+    }
 }
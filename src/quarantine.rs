@@ -0,0 +1,274 @@
+// --- CORRUPT-RECORD QUARANTINE ---
+// Stored values that no longer parse into a `Ritual` are moved out of RITUALS_TABLE
+// into QUARANTINE_TABLE, keeping the raw JSON and the parse error so they can be
+// inspected, repaired or purged instead of silently vanishing from the API.
+
+use axum::{
+    body::Bytes,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use colored::*;
+use redb::{Database, ReadableTable, TableDefinition};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+use crate::error::KernelError;
+use crate::{index, schema};
+use crate::{Ritual, RITUALS_TABLE};
+
+const QUARANTINE_TABLE: TableDefinition<&str, &str> = TableDefinition::new("quarantine");
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct QuarantinedRecord {
+    id: String,
+    raw: String,
+    error: String,
+    quarantined_at: DateTime<Utc>,
+}
+
+// A RITUALS_TABLE entry that failed to parse during a read
+#[derive(Debug, Clone)]
+pub struct CorruptRecord {
+    pub id: String,
+    pub raw: String,
+    pub error: String,
+}
+
+// Parses a stored value, also rejecting records filed under the wrong key
+pub fn parse_stored(key: &str, raw: &str) -> Result<Ritual, String> {
+    let ritual: Ritual = serde_json::from_str(raw).map_err(|e| e.to_string())?;
+    if ritual.id != key {
+        return Err(format!("stored id '{}' does not match key '{}'", ritual.id, key));
+    }
+    Ok(ritual)
+}
+
+// Moves corrupt records into quarantine. A record is only moved if the stored
+// value is still the one that failed, so a concurrent repair is never clobbered.
+pub fn quarantine(db: &Database, records: Vec<CorruptRecord>) -> Result<usize, KernelError> {
+    if records.is_empty() {
+        return Ok(0);
+    }
+
+    let write_txn = db.begin_write()?;
+    let mut moved = 0;
+    for record in records {
+        let unchanged = write_txn
+            .open_table(RITUALS_TABLE)?
+            .get(record.id.as_str())?
+            .is_some_and(|v| v.value() == record.raw);
+        if !unchanged {
+            continue;
+        }
+
+        let entry = QuarantinedRecord {
+            id: record.id.clone(),
+            raw: record.raw,
+            error: record.error,
+            quarantined_at: Utc::now(),
+        };
+        let json = serde_json::to_string(&entry)?;
+        write_txn.open_table(QUARANTINE_TABLE)?.insert(entry.id.as_str(), json.as_str())?;
+        index::remove_ritual(&write_txn, &entry.id)?;
+        moved += 1;
+    }
+    write_txn.commit()?;
+
+    if moved > 0 {
+        eprintln!("{} moved {} corrupt ritual(s) to quarantine", "QUARANTINE".yellow().bold(), moved);
+    }
+    Ok(moved)
+}
+
+pub fn list(db: &Database) -> Result<Vec<QuarantinedRecord>, KernelError> {
+    let read_txn = db.begin_read()?;
+    let table = match read_txn.open_table(QUARANTINE_TABLE) {
+        Ok(t) => t,
+        Err(redb::TableError::TableDoesNotExist(_)) => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut records = Vec::new();
+    for item in table.iter()? {
+        let (_, value) = item?;
+        records.push(serde_json::from_str(value.value())?);
+    }
+    Ok(records)
+}
+
+// Puts a quarantined record back into RITUALS_TABLE, either by re-parsing the raw
+// JSON (e.g. after a schema upgrade) or by using a corrected ritual from the caller.
+pub fn restore(db: &Database, id: &str, replacement: Option<Ritual>) -> Result<Ritual, KernelError> {
+    let write_txn = db.begin_write()?;
+    let ritual = {
+        let mut quarantine = write_txn.open_table(QUARANTINE_TABLE)?;
+
+        let record: QuarantinedRecord = match quarantine.get(id)? {
+            Some(v) => serde_json::from_str(v.value())?,
+            None => return Err(KernelError::not_found("quarantined record", id)),
+        };
+
+        let ritual = match replacement {
+            Some(r) => r,
+            None => parse_stored(id, &record.raw)
+                .or_else(|_| parse_stored(id, &schema::upgrade_quarantined(&record.raw)))
+                .map_err(|e| {
+                    KernelError::Validation(vec![format!("stored JSON still does not parse: {}", e)])
+                })?,
+        };
+
+        if ritual.id != id {
+            return Err(KernelError::Validation(vec![format!(
+                "ritual id '{}' does not match quarantined id '{}'",
+                ritual.id, id
+            )]));
+        }
+        let problems = ritual.validate();
+        if !problems.is_empty() {
+            return Err(KernelError::Validation(problems));
+        }
+        if write_txn.open_table(RITUALS_TABLE)?.get(id)?.is_some() {
+            return Err(KernelError::Conflict(format!(
+                "A ritual with id '{}' already exists; purge the quarantined copy instead",
+                id
+            )));
+        }
+
+        index::put_ritual(&write_txn, &ritual)?;
+        quarantine.remove(id)?;
+        ritual
+    };
+    write_txn.commit()?;
+    Ok(ritual)
+}
+
+// Deletes one quarantined record (or all of them when `id` is None)
+pub fn purge(db: &Database, id: Option<&str>) -> Result<usize, KernelError> {
+    let write_txn = db.begin_write()?;
+    let purged = {
+        let mut quarantine = write_txn.open_table(QUARANTINE_TABLE)?;
+        match id {
+            Some(id) => usize::from(quarantine.remove(id)?.is_some()),
+            None => {
+                let keys: Vec<String> = quarantine
+                    .iter()?
+                    .map(|item| item.map(|(k, _)| k.value().to_string()))
+                    .collect::<Result<_, _>>()?;
+                for key in &keys {
+                    quarantine.remove(key.as_str())?;
+                }
+                keys.len()
+            }
+        }
+    };
+    write_txn.commit()?;
+    Ok(purged)
+}
+
+// Full scan of RITUALS_TABLE that quarantines everything unparsable
+pub fn scan(db: &Database) -> Result<usize, KernelError> {
+    let corrupt = {
+        let read_txn = db.begin_read()?;
+        let table = match read_txn.open_table(RITUALS_TABLE) {
+            Ok(t) => t,
+            Err(redb::TableError::TableDoesNotExist(_)) => return Ok(0),
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut corrupt = Vec::new();
+        for item in table.iter()? {
+            let (key, value) = item?;
+            if let Err(error) = parse_stored(key.value(), value.value()) {
+                corrupt.push(CorruptRecord {
+                    id: key.value().to_string(),
+                    raw: value.value().to_string(),
+                    error,
+                });
+            }
+        }
+        corrupt
+    };
+    quarantine(db, corrupt)
+}
+
+// --- CLI: `culture-kernel doctor` ---
+pub fn doctor_cli(db: &Arc<Database>, restore_all: bool, purge_all: bool) -> anyhow::Result<()> {
+    println!("{}", " KERNEL DOCTOR ".on_blue().white().bold());
+
+    let moved = scan(db)?;
+    println!("Scanned rituals: {} newly quarantined", moved);
+    println!("Rebuilt indexes: {} rituals indexed", index::rebuild(db)?);
+
+    if purge_all {
+        let purged = purge(db, None)?;
+        println!("{} {} quarantined record(s)", "Purged".red().bold(), purged);
+        return Ok(());
+    }
+
+    let records = list(db)?;
+    if records.is_empty() {
+        println!("{}", "Quarantine is empty. All rituals parse.".green());
+        return Ok(());
+    }
+
+    for record in records {
+        if restore_all {
+            match restore(db, &record.id, None) {
+                Ok(_) => println!("{} {}", "RESTORED".green().bold(), record.id.cyan()),
+                Err(e) => println!("{} {} ({})", "STILL BROKEN".red().bold(), record.id.cyan(), e),
+            }
+            continue;
+        }
+
+        println!("{} {}", "QUARANTINED".yellow().bold(), record.id.cyan());
+        println!("  since: {}", record.quarantined_at.to_rfc3339());
+        println!("  error: {}", record.error.italic());
+        println!("  raw:   {}", record.raw);
+    }
+    Ok(())
+}
+
+// --- API: /admin/quarantine ---
+
+// GET /admin/quarantine
+pub async fn api_list(State(db): State<Arc<Database>>) -> Result<Response, KernelError> {
+    Ok(Json(list(&db)?).into_response())
+}
+
+// POST /admin/quarantine/{id}/restore
+// Empty body: re-parse the stored JSON. JSON body: a corrected Ritual to store instead.
+pub async fn api_restore(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<String>,
+    body: Bytes,
+) -> Result<Response, KernelError> {
+    let replacement = if body.iter().all(u8::is_ascii_whitespace) {
+        None
+    } else {
+        Some(serde_json::from_slice::<Ritual>(&body).map_err(|e| KernelError::Validation(vec![e.to_string()]))?)
+    };
+
+    let ritual = restore(&db, &id, replacement)?;
+    Ok(Json(ritual).into_response())
+}
+
+// DELETE /admin/quarantine/{id}
+pub async fn api_purge_one(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<String>,
+) -> Result<Response, KernelError> {
+    match purge(&db, Some(&id))? {
+        0 => Err(KernelError::not_found("quarantined record", id)),
+        _ => Ok(StatusCode::NO_CONTENT.into_response()),
+    }
+}
+
+// DELETE /admin/quarantine
+pub async fn api_purge_all(State(db): State<Arc<Database>>) -> Result<Response, KernelError> {
+    let purged = purge(&db, None)?;
+    Ok(Json(serde_json::json!({ "purged": purged })).into_response())
+}
@@ -0,0 +1,415 @@
+// --- RECURRENCE RULES ---
+// A practical subset of RFC 5545 RRULEs, enough for how rituals recur:
+// FREQ (DAILY, WEEKLY, MONTHLY, YEARLY), INTERVAL, BYDAY (with ordinals such as -1FR
+// for MONTHLY/YEARLY), BYMONTHDAY (negative counts from the month's end), BYMONTH,
+// COUNT and UNTIL. Weeks start on Monday. Occurrences keep the time of day of DTSTART.
+// As in RFC 5545, a YEARLY rule without BYMONTH expands BYMONTHDAY over every month
+// and counts BYDAY ordinals (20MO, -1SU) within the year rather than the month.
+
+use chrono::{Datelike, Duration, Months, NaiveDate, NaiveDateTime, Weekday};
+
+// Safety net against rules whose filters can never match (e.g. BYMONTHDAY=31;BYMONTH=2)
+const MAX_PERIODS: u32 = 50_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    freq: Frequency,
+    interval: u32,
+    // (ordinal, weekday): ordinal 2 = second, -1 = last, None = every
+    by_day: Vec<(Option<i32>, Weekday)>,
+    by_month_day: Vec<i32>,
+    by_month: Vec<u32>,
+    count: Option<u32>,
+    until: Option<NaiveDateTime>,
+}
+
+fn weekday(code: &str) -> Option<Weekday> {
+    Some(match code {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+fn list<T>(value: &str, parse: impl Fn(&str) -> Option<T>) -> Option<Vec<T>> {
+    value.split(',').map(|v| parse(v.trim())).collect()
+}
+
+fn parse_until(value: &str) -> Option<NaiveDateTime> {
+    let value = value.trim_end_matches('Z');
+    NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S")
+        .ok()
+        .or_else(|| NaiveDate::parse_from_str(value, "%Y%m%d").ok().and_then(|d| d.and_hms_opt(23, 59, 59)))
+}
+
+// Rewrites UNTIL as a UTC date-time for iCalendar export. RFC 5545 requires UNTIL to have
+// the value type of DTSTART, which is always a UTC date-time here; a date-only UNTIL
+// already means the end of that day, so "UNTIL=20250108" becomes "UNTIL=20250108T235959Z".
+pub fn utc_until(rrule: &str) -> String {
+    rrule
+        .trim()
+        .split(';')
+        .map(|part| match part.split_once('=') {
+            Some((key, value)) if key.trim().eq_ignore_ascii_case("UNTIL") => match parse_until(value.trim()) {
+                Some(until) => format!("{}={}", key, until.format("%Y%m%dT%H%M%SZ")),
+                None => part.to_string(),
+            },
+            _ => part.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+impl Rule {
+    pub fn parse(text: &str) -> Result<Rule, String> {
+        let text = text.trim();
+        let text = text.strip_prefix("RRULE:").unwrap_or(text);
+
+        let mut freq = None;
+        let mut rule = Rule {
+            freq: Frequency::Daily,
+            interval: 1,
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_month: Vec::new(),
+            count: None,
+            until: None,
+        };
+
+        for part in text.split(';').filter(|p| !p.is_empty()) {
+            let (key, value) = part.split_once('=').ok_or_else(|| format!("'{}' is not KEY=VALUE", part))?;
+            let bad = || format!("invalid {} '{}'", key, value);
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    freq = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return Err(format!("unsupported FREQ '{}' (use DAILY, WEEKLY, MONTHLY or YEARLY)", value)),
+                    })
+                }
+                "INTERVAL" => rule.interval = value.parse().ok().filter(|i| *i > 0).ok_or_else(bad)?,
+                "COUNT" => rule.count = Some(value.parse().ok().filter(|c| *c > 0).ok_or_else(bad)?),
+                "UNTIL" => rule.until = Some(parse_until(value).ok_or_else(bad)?),
+                "BYMONTH" => {
+                    rule.by_month = list(value, |v| v.parse().ok().filter(|m| (1..=12).contains(m))).ok_or_else(bad)?
+                }
+                "BYMONTHDAY" => {
+                    rule.by_month_day = list(value, |v| {
+                        v.parse().ok().filter(|d: &i32| *d != 0 && d.abs() <= 31)
+                    })
+                    .ok_or_else(bad)?
+                }
+                "BYDAY" => {
+                    rule.by_day = list(value, |v| {
+                        let v = v.to_ascii_uppercase();
+                        let (ordinal, code) = v.split_at(v.len().checked_sub(2)?);
+                        let ordinal = match ordinal {
+                            "" => None,
+                            n => Some(n.trim_start_matches('+').parse().ok().filter(|n: &i32| *n != 0 && n.abs() <= 53)?),
+                        };
+                        Some((ordinal, weekday(code)?))
+                    })
+                    .ok_or_else(bad)?
+                }
+                "WKST" if value.eq_ignore_ascii_case("MO") => {}
+                other => return Err(format!("unsupported RRULE part '{}'", other)),
+            }
+        }
+
+        rule.freq = freq.ok_or("RRULE needs a FREQ")?;
+        if rule.count.is_some() && rule.until.is_some() {
+            return Err("COUNT and UNTIL cannot both be set".to_string());
+        }
+        if matches!(rule.freq, Frequency::Daily | Frequency::Weekly) && rule.by_day.iter().any(|(n, _)| n.is_some()) {
+            return Err("BYDAY ordinals (e.g. -1FR) only apply to MONTHLY and YEARLY rules".to_string());
+        }
+        // RFC 5545 leaves ordinals undefined when BYDAY only limits a yearly BYMONTHDAY set
+        if rule.freq == Frequency::Yearly
+            && rule.by_month.is_empty()
+            && !rule.by_month_day.is_empty()
+            && rule.by_day.iter().any(|(n, _)| n.is_some())
+        {
+            return Err("YEARLY rules cannot combine BYDAY ordinals with BYMONTHDAY unless BYMONTH is set".to_string());
+        }
+        Ok(rule)
+    }
+
+    // Start of the k-th period after the one containing `start`
+    fn period(&self, start: NaiveDate, k: u32) -> Option<NaiveDate> {
+        let steps = k.checked_mul(self.interval)?;
+        match self.freq {
+            Frequency::Daily => start.checked_add_signed(Duration::days(steps.into())),
+            Frequency::Weekly => {
+                let monday = start - Duration::days(start.weekday().num_days_from_monday().into());
+                monday.checked_add_signed(Duration::weeks(steps.into()))
+            }
+            Frequency::Monthly => start.with_day(1)?.checked_add_months(Months::new(steps)),
+            Frequency::Yearly => start.with_day(1)?.with_month(1)?.checked_add_months(Months::new(steps.checked_mul(12)?)),
+        }
+    }
+
+    // Days in one month that satisfy BYDAY / BYMONTHDAY (or `fallback_day` when neither is set)
+    fn days_in_month(&self, first: NaiveDate, fallback_day: u32) -> Vec<NaiveDate> {
+        let next = first + Months::new(1);
+        let days: Vec<NaiveDate> = first.iter_days().take_while(|d| *d < next).collect();
+        let len = days.len() as i32;
+
+        let by_month_day = |d: &NaiveDate| {
+            self.by_month_day.is_empty()
+                || self.by_month_day.iter().any(|&n| {
+                    let day = d.day() as i32;
+                    if n > 0 { day == n } else { day == len + n + 1 }
+                })
+        };
+        let by_day = |d: &NaiveDate| {
+            self.by_day.is_empty()
+                || self.by_day.iter().any(|&(ordinal, wd)| {
+                    if d.weekday() != wd {
+                        return false;
+                    }
+                    match ordinal {
+                        None => true,
+                        Some(n) if n > 0 => (d.day() as i32 - 1) / 7 + 1 == n,
+                        Some(n) => (len - d.day() as i32) / 7 + 1 == -n,
+                    }
+                })
+        };
+
+        if self.by_day.is_empty() && self.by_month_day.is_empty() {
+            return days.into_iter().filter(|d| d.day() == fallback_day).collect();
+        }
+        days.into_iter().filter(|d| by_month_day(d) && by_day(d)).collect()
+    }
+
+    // Days of one year that satisfy BYDAY, ordinals counted within the year
+    fn days_in_year(&self, first: NaiveDate) -> Vec<NaiveDate> {
+        let next = first + Months::new(12);
+        let days: Vec<NaiveDate> = first.iter_days().take_while(|d| *d < next).collect();
+        let len = days.len() as i32;
+        days.into_iter()
+            .filter(|d| {
+                let day = d.ordinal() as i32;
+                self.by_day.iter().any(|&(ordinal, wd)| {
+                    d.weekday() == wd
+                        && match ordinal {
+                            None => true,
+                            Some(n) if n > 0 => (day - 1) / 7 + 1 == n,
+                            Some(n) => (len - day) / 7 + 1 == -n,
+                        }
+                })
+            })
+            .collect()
+    }
+
+    // Candidate dates of one period, in order
+    fn candidates(&self, period: NaiveDate, dtstart: NaiveDate) -> Vec<NaiveDate> {
+        let in_month = |d: &NaiveDate| self.by_month.is_empty() || self.by_month.contains(&d.month());
+        match self.freq {
+            Frequency::Daily => {
+                let wd_ok = self.by_day.is_empty() || self.by_day.iter().any(|(_, wd)| *wd == period.weekday());
+                let md_ok = self.by_month_day.is_empty() || self.days_in_month(period.with_day(1).unwrap_or(period), 0).contains(&period);
+                if wd_ok && md_ok && in_month(&period) { vec![period] } else { Vec::new() }
+            }
+            Frequency::Weekly => {
+                let weekdays: Vec<Weekday> = if self.by_day.is_empty() {
+                    vec![dtstart.weekday()]
+                } else {
+                    self.by_day.iter().map(|(_, wd)| *wd).collect()
+                };
+                let mut days: Vec<NaiveDate> = (0..7)
+                    .map(|i| period + Duration::days(i))
+                    .filter(|d| weekdays.contains(&d.weekday()) && in_month(d))
+                    .collect();
+                days.dedup();
+                days
+            }
+            Frequency::Monthly => {
+                if !in_month(&period) {
+                    return Vec::new();
+                }
+                self.days_in_month(period, dtstart.day())
+            }
+            Frequency::Yearly => {
+                let months: Vec<u32> = match (self.by_month.is_empty(), self.by_month_day.is_empty(), self.by_day.is_empty()) {
+                    (false, _, _) => self.by_month.clone(),
+                    (true, false, _) => (1..=12).collect(),
+                    (true, true, false) => return self.days_in_year(period),
+                    (true, true, true) => vec![dtstart.month()],
+                };
+                let mut days = Vec::new();
+                for month in months {
+                    if let Some(first) = period.with_month(month) {
+                        days.extend(self.days_in_month(first, dtstart.day()));
+                    }
+                }
+                days.sort();
+                days
+            }
+        }
+    }
+
+    // Occurrences at or after `from`, at most `limit`, none starting after `to`
+    pub fn occurrences(&self, dtstart: NaiveDateTime, from: NaiveDateTime, to: NaiveDateTime, limit: usize) -> Vec<NaiveDateTime> {
+        let mut found = Vec::new();
+        let mut produced = 0u32;
+        let time = dtstart.time();
+
+        for k in 0..MAX_PERIODS {
+            let Some(period) = self.period(dtstart.date(), k) else {
+                break;
+            };
+            if period.and_time(time) > to + Duration::days(366) {
+                break;
+            }
+
+            for date in self.candidates(period, dtstart.date()) {
+                let at = date.and_time(time);
+                if at < dtstart {
+                    continue;
+                }
+                if self.until.is_some_and(|until| at > until) || at > to {
+                    return found;
+                }
+                produced += 1;
+                if self.count.is_some_and(|count| produced > count) {
+                    return found;
+                }
+                if at >= from {
+                    found.push(at);
+                    if found.len() >= limit {
+                        return found;
+                    }
+                }
+            }
+        }
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(text: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M").unwrap()
+    }
+
+    // The first `n` occurrence dates of `rrule` from `dtstart`
+    fn dates(rrule: &str, dtstart: &str, n: usize) -> Vec<String> {
+        let start = at(dtstart);
+        Rule::parse(rrule)
+            .unwrap()
+            .occurrences(start, start, at("2035-01-01T00:00"), n)
+            .iter()
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .collect()
+    }
+
+    #[test]
+    fn export_writes_until_as_utc_date_time() {
+        assert_eq!(utc_until("FREQ=WEEKLY;BYDAY=MO;UNTIL=20250108"), "FREQ=WEEKLY;BYDAY=MO;UNTIL=20250108T235959Z");
+        assert_eq!(utc_until("FREQ=DAILY;UNTIL=20250108T090000"), "FREQ=DAILY;UNTIL=20250108T090000Z");
+        assert_eq!(utc_until("FREQ=DAILY;COUNT=3"), "FREQ=DAILY;COUNT=3");
+    }
+
+    #[test]
+    fn monthly_last_friday() {
+        assert_eq!(
+            dates("FREQ=MONTHLY;BYDAY=-1FR", "2025-01-31T13:00", 3),
+            ["2025-01-31", "2025-02-28", "2025-03-28"]
+        );
+    }
+
+    #[test]
+    fn monthly_month_day_skips_short_months() {
+        assert_eq!(
+            dates("FREQ=MONTHLY;BYMONTHDAY=31", "2025-01-31T09:00", 3),
+            ["2025-01-31", "2025-03-31", "2025-05-31"]
+        );
+        assert_eq!(
+            dates("FREQ=MONTHLY;BYMONTHDAY=-1", "2025-01-31T09:00", 2),
+            ["2025-01-31", "2025-02-28"]
+        );
+    }
+
+    #[test]
+    fn yearly_without_by_parts_repeats_dtstart() {
+        assert_eq!(
+            dates("FREQ=YEARLY", "2024-02-29T10:00", 2),
+            ["2024-02-29", "2028-02-29"]
+        );
+    }
+
+    #[test]
+    fn yearly_month_day_expands_over_every_month() {
+        assert_eq!(
+            dates("FREQ=YEARLY;BYMONTHDAY=1", "2025-01-01T10:00", 4),
+            ["2025-01-01", "2025-02-01", "2025-03-01", "2025-04-01"]
+        );
+    }
+
+    #[test]
+    fn yearly_day_ordinals_count_within_the_year() {
+        // 2025's first Monday is 6 January
+        assert_eq!(dates("FREQ=YEARLY;BYDAY=20MO", "2025-01-01T10:00", 2), ["2025-05-19", "2026-05-18"]);
+        assert_eq!(dates("FREQ=YEARLY;BYDAY=-1SU", "2025-01-01T10:00", 1), ["2025-12-28"]);
+        assert_eq!(
+            dates("FREQ=YEARLY;BYDAY=MO", "2025-01-01T10:00", 3),
+            ["2025-01-06", "2025-01-13", "2025-01-20"]
+        );
+    }
+
+    #[test]
+    fn yearly_day_ordinals_with_by_month_count_within_the_month() {
+        assert_eq!(
+            dates("FREQ=YEARLY;BYMONTH=3;BYDAY=-1TH", "2025-03-27T18:00", 2),
+            ["2025-03-27", "2026-03-26"]
+        );
+    }
+
+    #[test]
+    fn interval_count_and_until() {
+        assert_eq!(
+            dates("FREQ=WEEKLY;INTERVAL=8", "2025-01-06T09:00", 2),
+            ["2025-01-06", "2025-03-03"]
+        );
+        assert_eq!(dates("FREQ=DAILY;COUNT=2", "2025-01-06T09:00", 10), ["2025-01-06", "2025-01-07"]);
+        assert_eq!(
+            dates("FREQ=WEEKLY;BYDAY=MO,WE;UNTIL=20250108", "2025-01-06T09:00", 10),
+            ["2025-01-06", "2025-01-08"]
+        );
+    }
+
+    #[test]
+    fn occurrences_before_dtstart_are_skipped() {
+        assert_eq!(
+            dates("FREQ=MONTHLY;BYMONTHDAY=1,15", "2025-01-10T09:00", 2),
+            ["2025-01-15", "2025-02-01"]
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_combinations() {
+        assert!(Rule::parse("FREQ=WEEKLY;BYDAY=1MO").is_err());
+        assert!(Rule::parse("FREQ=YEARLY;BYDAY=1MO;BYMONTHDAY=1,2,3,4,5,6,7").is_err());
+        assert!(Rule::parse("FREQ=YEARLY;BYMONTH=1;BYDAY=1MO;BYMONTHDAY=1,2,3,4,5,6,7").is_ok());
+        assert!(Rule::parse("FREQ=DAILY;COUNT=2;UNTIL=20250101").is_err());
+        assert!(Rule::parse("INTERVAL=2").is_err());
+        assert!(Rule::parse("FREQ=HOURLY").is_err());
+    }
+}
@@ -0,0 +1,531 @@
+// --- OUTPUT POLYMORPHISM ---
+// Content negotiation and every renderer the API can answer with.
+// Priority: ?format= override > Accept header > User-Agent heuristic (curl/wget) > JSON.
+
+use axum::{
+    http::{header, HeaderMap, HeaderValue},
+    response::{IntoResponse, Response},
+    Json,
+};
+use colored::*;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+use crate::error::KernelError;
+use crate::schedule::ScheduleListing;
+use crate::search::SearchResults;
+use crate::sessions::{self, SessionStats};
+use crate::Ritual;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Json,
+    Plain,
+    Ansi,
+    Html,
+    Markdown,
+}
+
+impl OutputFormat {
+    // Values accepted by ?format=
+    fn from_param(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "json" => Some(Self::Json),
+            "text" | "plain" | "txt" => Some(Self::Plain),
+            "ansi" | "terminal" => Some(Self::Ansi),
+            "html" => Some(Self::Html),
+            "markdown" | "md" => Some(Self::Markdown),
+            _ => None,
+        }
+    }
+
+    fn from_media_type(media_type: &str) -> Option<Self> {
+        match media_type {
+            "application/json" | "application/*" => Some(Self::Json),
+            "text/plain" | "text/*" => Some(Self::Plain),
+            "text/x-ansi" => Some(Self::Ansi),
+            "text/html" | "application/xhtml+xml" => Some(Self::Html),
+            "text/markdown" | "text/x-markdown" => Some(Self::Markdown),
+            _ => None,
+        }
+    }
+
+    fn content_type(self) -> &'static str {
+        match self {
+            Self::Json => "application/json",
+            Self::Plain => "text/plain; charset=utf-8",
+            Self::Ansi => "text/x-ansi; charset=utf-8",
+            Self::Html => "text/html; charset=utf-8",
+            Self::Markdown => "text/markdown; charset=utf-8",
+        }
+    }
+}
+
+// Query parameter shared by every negotiating endpoint
+#[derive(Debug, Deserialize, Default)]
+pub struct FormatParam {
+    pub format: Option<String>,
+}
+
+// Picks the response format for a request
+pub fn negotiate(headers: &HeaderMap, format: Option<&str>) -> Result<OutputFormat, KernelError> {
+    if let Some(value) = format.filter(|f| !f.is_empty()) {
+        return OutputFormat::from_param(value).ok_or_else(|| KernelError::UnsupportedFormat(value.to_string()));
+    }
+
+    let accept = headers
+        .get(header::ACCEPT)
+        .and_then(|h| h.to_str().ok())
+        .unwrap_or("");
+
+    match from_accept(accept) {
+        Accepted::Format(f) => Ok(f),
+        Accepted::Anything => Ok(if is_terminal_client(headers) {
+            OutputFormat::Ansi
+        } else {
+            OutputFormat::Json
+        }),
+        Accepted::Nothing => Err(KernelError::NotAcceptable),
+    }
+}
+
+enum Accepted {
+    Format(OutputFormat),
+    // No Accept header or a wildcard won: fall back to the User-Agent heuristic
+    Anything,
+    Nothing,
+}
+
+fn from_accept(accept: &str) -> Accepted {
+    let mut ranges: Vec<(&str, f32)> = accept
+        .split(',')
+        .filter_map(|part| {
+            let mut pieces = part.split(';');
+            let media_type = pieces.next()?.trim();
+            if media_type.is_empty() {
+                return None;
+            }
+            let q = pieces
+                .filter_map(|p| p.trim().strip_prefix("q="))
+                .find_map(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+            Some((media_type, q))
+        })
+        .filter(|(_, q)| *q > 0.0)
+        .collect();
+
+    if ranges.is_empty() {
+        return Accepted::Anything;
+    }
+
+    // Stable sort keeps the client's ordering among equal weights
+    ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    for (media_type, _) in ranges {
+        if media_type == "*/*" {
+            return Accepted::Anything;
+        }
+        if let Some(format) = OutputFormat::from_media_type(&media_type.to_lowercase()) {
+            return Accepted::Format(format);
+        }
+    }
+    Accepted::Nothing
+}
+
+// Detect User-Agent (Is it Curl?)
+fn is_terminal_client(headers: &HeaderMap) -> bool {
+    let user_agent = headers
+        .get(header::USER_AGENT)
+        .and_then(|h| h.to_str().ok())
+        .unwrap_or("unknown")
+        .to_lowercase();
+
+    user_agent.contains("curl") || user_agent.contains("wget")
+}
+
+// --- RESPONSES ---
+
+// `stats` (session counts per ritual id) only shows up in terminal output, so it is only
+// computed for the ANSI and plain formats
+pub fn rituals_response(
+    format: OutputFormat,
+    rituals: Vec<Ritual>,
+    stats: impl FnOnce() -> BTreeMap<String, SessionStats>,
+) -> Response {
+    let body = match format {
+        OutputFormat::Json => return with_vary(Json(rituals).into_response()),
+        OutputFormat::Ansi => ansi_listing(&rituals, &stats()),
+        OutputFormat::Plain => strip_ansi(&ansi_listing(&rituals, &stats())),
+        OutputFormat::Markdown => markdown_listing(&rituals),
+        OutputFormat::Html => html_page("Culture Kernel :: Active Rituals", &html_listing(&rituals)),
+    };
+    text_response(format, body)
+}
+
+pub fn ritual_response(format: OutputFormat, ritual: Ritual, stats: impl FnOnce() -> Option<SessionStats>) -> Response {
+    let body = match format {
+        OutputFormat::Json => return with_vary(Json(ritual).into_response()),
+        OutputFormat::Ansi => ansi_card(&ritual, stats().as_ref()),
+        OutputFormat::Plain => strip_ansi(&ansi_card(&ritual, stats().as_ref())),
+        OutputFormat::Markdown => markdown_ritual(&ritual),
+        OutputFormat::Html => html_page(&ritual.name, &html_card(&ritual)),
+    };
+    text_response(format, body)
+}
+
+pub fn search_response(format: OutputFormat, results: &SearchResults) -> Response {
+    let body = match format {
+        OutputFormat::Json => return with_vary(Json(results).into_response()),
+        OutputFormat::Ansi => ansi_search(results),
+        OutputFormat::Plain => strip_ansi(&ansi_search(results)),
+        OutputFormat::Markdown => markdown_search(results),
+        OutputFormat::Html => html_page(&format!("Search: {}", results.query), &html_search(results)),
+    };
+    text_response(format, body)
+}
+
+pub fn schedule_response(format: OutputFormat, listing: &ScheduleListing) -> Response {
+    let body = match format {
+        OutputFormat::Json => return with_vary(Json(listing).into_response()),
+        OutputFormat::Ansi => ansi_schedule(listing),
+        OutputFormat::Plain => strip_ansi(&ansi_schedule(listing)),
+        OutputFormat::Markdown => markdown_schedule(listing),
+        OutputFormat::Html => html_page("Culture Kernel :: Schedule", &html_schedule(listing)),
+    };
+    text_response(format, body)
+}
+
+fn text_response(format: OutputFormat, body: String) -> Response {
+    let mut response = body.into_response();
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static(format.content_type()),
+    );
+    with_vary(response)
+}
+
+// Caches must key on the headers that drove negotiation
+fn with_vary(mut response: Response) -> Response {
+    response.headers_mut().insert(
+        header::VARY,
+        HeaderValue::from_static("Accept, User-Agent"),
+    );
+    response
+}
+
+// --- ANSI / PLAIN TEXT ---
+
+fn ansi_listing(rituals: &[Ritual], stats: &BTreeMap<String, SessionStats>) -> String {
+    // Render ANSI Art Table (Updated for new Schema)
+    let mut output = String::new();
+    output.push_str(&format!("{}\n", "╔════════════════════════════════════════════════╗".bright_cyan()));
+    output.push_str(&format!("║  {}  ║\n", "CULTURE KERNEL :: ACTIVE RITUALS".yellow().bold()));
+    output.push_str(&format!("{}\n\n", "╚════════════════════════════════════════════════╝".bright_cyan()));
+
+    for r in rituals {
+        output.push_str(&ansi_card(r, stats.get(&r.id)));
+    }
+    output
+}
+
+// One ANSI card per ritual, shared by the list and single-lookup endpoints
+fn ansi_card(r: &Ritual, stats: Option<&SessionStats>) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("> {}\n", r.name.green().bold()));
+    output.push_str(&format!("  ID:       {}\n", r.id.cyan()));
+    output.push_str(&format!("  ORIGIN:   {}\n", r.origin_culture));
+    output.push_str(&format!("  BUG FIX:  {}\n", r.bug_fixed.italic()));
+    output.push_str(&format!("  SESSIONS: {}\n", sessions::describe(stats)));
+
+    // Loop through the modern_script steps
+    output.push_str("  SCRIPT:\n");
+    for (key, val) in sorted_steps(r) {
+        output.push_str(&format!("    - {}: {}\n", key.to_uppercase(), val));
+    }
+    output.push_str("\n──────────────────────────────────────────────────\n\n");
+    output
+}
+
+pub fn ansi_search(results: &SearchResults) -> String {
+    let mut output = format!(
+        "{} {} for \"{}\"\n\n",
+        results.total.to_string().bold(),
+        if results.total == 1 { "match" } else { "matches" },
+        results.query
+    );
+    for (rank, hit) in results.hits.iter().enumerate() {
+        output.push_str(&format!(
+            "{}. {} {} {}\n",
+            rank + 1,
+            hit.name.green().bold(),
+            hit.id.cyan(),
+            format!("({:.2})", hit.score).dimmed()
+        ));
+        for snippet in &hit.snippets {
+            let text = snippet.highlight(|t| t.yellow().bold().to_string(), str::to_string);
+            output.push_str(&format!("   {} {}\n", format!("{}:", snippet.field).dimmed(), text));
+        }
+        output.push('\n');
+    }
+    output
+}
+
+pub fn ansi_schedule(listing: &ScheduleListing) -> String {
+    let now = listing.computed_at;
+    let mut output = format!(
+        "{} (next {} days, UTC)\n\n",
+        "UPCOMING RITUALS".yellow().bold(),
+        listing.horizon_days
+    );
+    if listing.occurrences.is_empty() {
+        output.push_str("Nothing scheduled in this window.\n");
+    }
+    for o in &listing.occurrences {
+        let state = if o.start <= now {
+            "on now".green().bold().to_string()
+        } else if o.in_lead_window(now) {
+            "prepare now".yellow().to_string()
+        } else {
+            format!("prepare from {}", o.prepare_from.format("%Y-%m-%d")).dimmed().to_string()
+        };
+        output.push_str(&format!(
+            "{} {}  {} {}  {}\n",
+            o.start.format("%a %Y-%m-%d %H:%M"),
+            format!("→ {}", o.end.format("%Y-%m-%d %H:%M")).dimmed(),
+            o.name.green().bold(),
+            o.ritual_id.cyan(),
+            state
+        ));
+    }
+    output
+}
+
+// Removes CSI escape sequences (ESC [ ... final byte) so text/plain is clean
+fn strip_ansi(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1b' && chars.peek() == Some(&'[') {
+            chars.next();
+            for c in chars.by_ref() {
+                if ('@'..='~').contains(&c) {
+                    break;
+                }
+            }
+        } else {
+            output.push(c);
+        }
+    }
+    output
+}
+
+// --- MARKDOWN ---
+// Served as text/markdown and rendered by termimad for `culture-kernel show`.
+
+fn markdown_listing(rituals: &[Ritual]) -> String {
+    let mut output = String::from("# Culture Kernel :: Active Rituals\n\n");
+    for r in rituals {
+        output.push_str(&markdown_section(r, 2));
+        output.push_str("\n---\n\n");
+    }
+    output
+}
+
+// A single ritual as a standalone document
+pub fn markdown_ritual(r: &Ritual) -> String {
+    markdown_section(r, 1)
+}
+
+fn markdown_section(r: &Ritual, level: usize) -> String {
+    let h = "#".repeat(level);
+    let mut output = String::new();
+
+    output.push_str(&format!("{} {}\n\n", h, r.name));
+    output.push_str(&format!("*{}* · {} · `{}`\n\n", r.origin_culture, r.category, r.id));
+    output.push_str(&format!("**Bug fixed:** {}\n\n", r.bug_fixed));
+    output.push_str(&format!("**Mechanism:** {}\n\n", r.mechanism));
+    if let Some(schedule) = &r.schedule {
+        output.push_str(&format!(
+            "**Schedule:** `{}` from {} UTC, {} min, prepare {} day(s) ahead\n\n",
+            schedule.rrule,
+            schedule.start.format("%Y-%m-%d %H:%M"),
+            schedule.duration_minutes,
+            schedule.lead_time_days
+        ));
+    }
+
+    output.push_str(&format!("{}# Modern Script\n\n", h));
+    output.push_str("| Step | Instruction |\n");
+    output.push_str("|:-|:-|\n");
+    for (key, val) in sorted_steps(r) {
+        output.push_str(&format!(
+            "| **{}** | {} |\n",
+            table_cell(&step_title(key)),
+            table_cell(val)
+        ));
+    }
+
+    output.push_str(&format!("\n{}# Ethical Guardrails\n\n", h));
+    for guardrail in &r.ethical_guardrails {
+        output.push_str(&format!("- {}\n", guardrail));
+    }
+    output
+}
+
+fn markdown_search(results: &SearchResults) -> String {
+    let mut output = format!("# Search: {}\n\n{} match(es)\n\n", results.query, results.total);
+    for (rank, hit) in results.hits.iter().enumerate() {
+        output.push_str(&format!("{}. **{}** `{}` ({:.2})\n", rank + 1, hit.name, hit.id, hit.score));
+        for snippet in &hit.snippets {
+            let text = snippet.highlight(|t| format!("**{}**", t), str::to_string);
+            output.push_str(&format!("   - *{}:* {}\n", snippet.field, text));
+        }
+    }
+    output
+}
+
+fn markdown_schedule(listing: &ScheduleListing) -> String {
+    let mut output = format!("# Upcoming Rituals\n\nNext {} days, UTC.\n\n", listing.horizon_days);
+    output.push_str("| Starts | Ends | Ritual | Prepare from |\n");
+    output.push_str("|:-|:-|:-|:-|\n");
+    for o in &listing.occurrences {
+        output.push_str(&format!(
+            "| {} | {} | {} `{}` | {} |\n",
+            o.start.format("%Y-%m-%d %H:%M"),
+            o.end.format("%Y-%m-%d %H:%M"),
+            table_cell(&o.name),
+            o.ritual_id,
+            o.prepare_from.format("%Y-%m-%d")
+        ));
+    }
+    output
+}
+
+// modern_script is a HashMap; sorted so a ritual renders identically on every run
+fn sorted_steps(r: &Ritual) -> Vec<(&String, &String)> {
+    let mut steps: Vec<_> = r.modern_script.iter().collect();
+    steps.sort();
+    steps
+}
+
+// "vesting_period" -> "Vesting Period"
+pub fn step_title(key: &str) -> String {
+    key.split('_')
+        .filter(|w| !w.is_empty())
+        .map(|w| {
+            let mut chars = w.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Pipes and newlines would break the table row
+fn table_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', " ")
+}
+
+// --- HTML ---
+
+fn html_page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n<body>\n{}</body>\n</html>\n",
+        escape_html(title),
+        body
+    )
+}
+
+fn html_listing(rituals: &[Ritual]) -> String {
+    let mut output = String::from("<h1>Culture Kernel :: Active Rituals</h1>\n");
+    for r in rituals {
+        output.push_str(&html_card(r));
+    }
+    output
+}
+
+fn html_card(r: &Ritual) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("<article id=\"{}\">\n", escape_html(&r.id)));
+    output.push_str(&format!("<h2>{}</h2>\n", escape_html(&r.name)));
+    output.push_str("<dl>\n");
+    for (label, value) in [
+        ("ID", &r.id),
+        ("Origin", &r.origin_culture),
+        ("Category", &r.category),
+        ("Bug fixed", &r.bug_fixed),
+        ("Mechanism", &r.mechanism),
+    ] {
+        output.push_str(&format!("<dt>{}</dt><dd>{}</dd>\n", label, escape_html(value)));
+    }
+    output.push_str("</dl>\n<h3>Modern Script</h3>\n<ul>\n");
+    for (key, val) in sorted_steps(r) {
+        output.push_str(&format!("<li><strong>{}:</strong> {}</li>\n", escape_html(key), escape_html(val)));
+    }
+    output.push_str("</ul>\n<h3>Ethical Guardrails</h3>\n<ul>\n");
+    for guardrail in &r.ethical_guardrails {
+        output.push_str(&format!("<li>{}</li>\n", escape_html(guardrail)));
+    }
+    output.push_str("</ul>\n</article>\n");
+    output
+}
+
+fn html_search(results: &SearchResults) -> String {
+    let mut output = format!(
+        "<h1>Search: {}</h1>\n<p>{} match(es)</p>\n<ol>\n",
+        escape_html(&results.query),
+        results.total
+    );
+    for hit in &results.hits {
+        output.push_str(&format!(
+            "<li><strong>{}</strong> <code>{}</code> ({:.2})\n<ul>\n",
+            escape_html(&hit.name),
+            escape_html(&hit.id),
+            hit.score
+        ));
+        for snippet in &hit.snippets {
+            let text = snippet.highlight(|t| format!("<mark>{}</mark>", escape_html(t)), escape_html);
+            output.push_str(&format!("<li><em>{}:</em> {}</li>\n", escape_html(&snippet.field), text));
+        }
+        output.push_str("</ul>\n</li>\n");
+    }
+    output.push_str("</ol>\n");
+    output
+}
+
+fn html_schedule(listing: &ScheduleListing) -> String {
+    let mut output = format!(
+        "<h1>Upcoming Rituals</h1>\n<p>Next {} days, UTC.</p>\n<table>\n<tr><th>Starts</th><th>Ends</th><th>Ritual</th><th>Prepare from</th></tr>\n",
+        listing.horizon_days
+    );
+    for o in &listing.occurrences {
+        output.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td><td>{} <code>{}</code></td><td>{}</td></tr>\n",
+            o.start.format("%Y-%m-%d %H:%M"),
+            o.end.format("%Y-%m-%d %H:%M"),
+            escape_html(&o.name),
+            escape_html(&o.ritual_id),
+            o.prepare_from.format("%Y-%m-%d")
+        ));
+    }
+    output.push_str("</table>\n");
+    output
+}
+
+pub fn escape_html(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    for c in input.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&#39;"),
+            _ => output.push(c),
+        }
+    }
+    output
+}
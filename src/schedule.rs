@@ -0,0 +1,261 @@
+// --- RITUAL SCHEDULING ---
+// Rituals may carry a structured `schedule` (RRULE recurrence, duration, lead time)
+// next to the free-text timing in `modern_script`. In `serve` mode a background task
+// on the tokio runtime recomputes the upcoming occurrences of every scheduled ritual
+// once a minute, announces lead windows and starts on stdout, and serves the latest
+// snapshot at GET /schedule. The `schedule` CLI computes the same listing offline.
+// All times are UTC.
+
+use axum::{
+    extract::{Query, State},
+    http::HeaderMap,
+    response::{IntoResponse, Response},
+    Extension,
+};
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use colored::*;
+use redb::Database;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::{Arc, RwLock};
+
+use crate::error::KernelError;
+use crate::recurrence::Rule;
+use crate::render::{self, FormatParam};
+use crate::{load_ritual, load_rituals, Ritual, RitualFilter};
+
+const DEFAULT_HORIZON_DAYS: i64 = 30;
+const MAX_HORIZON_DAYS: i64 = 366;
+// Per ritual, so a DAILY rule cannot crowd everything else out of a year-long listing
+const MAX_PER_RITUAL: usize = 400;
+const REFRESH_SECONDS: u64 = 60;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Schedule {
+    // e.g. "FREQ=MONTHLY;BYDAY=-1FR" (see recurrence.rs for the supported subset)
+    pub rrule: String,
+    // First occurrence; also fixes the time of day
+    pub start: NaiveDateTime,
+    pub duration_minutes: u32,
+    // How many days ahead people should start preparing
+    #[serde(default)]
+    pub lead_time_days: u32,
+}
+
+impl Schedule {
+    pub fn validate(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if let Err(e) = Rule::parse(&self.rrule) {
+            problems.push(format!("schedule.rrule: {}", e));
+        }
+        if self.duration_minutes == 0 {
+            problems.push("schedule.duration_minutes must be greater than 0".to_string());
+        }
+        problems
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Occurrence {
+    pub ritual_id: String,
+    pub name: String,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+    // When the lead window opens (start minus lead time)
+    pub prepare_from: DateTime<Utc>,
+}
+
+impl Occurrence {
+    pub fn in_lead_window(&self, now: DateTime<Utc>) -> bool {
+        self.prepare_from <= now && now < self.start
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduleListing {
+    pub computed_at: DateTime<Utc>,
+    pub horizon_days: i64,
+    pub occurrences: Vec<Occurrence>,
+}
+
+impl ScheduleListing {
+    // A narrower view of a snapshot: shorter horizon and/or one ritual
+    fn narrowed(&self, horizon_days: i64, ritual: Option<&str>) -> ScheduleListing {
+        let until = self.computed_at + Duration::days(horizon_days);
+        ScheduleListing {
+            computed_at: self.computed_at,
+            horizon_days,
+            occurrences: self
+                .occurrences
+                .iter()
+                .filter(|o| o.start <= until && ritual.is_none_or(|r| o.ritual_id == r))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+// Upcoming occurrences (including ones in progress) of every scheduled ritual
+pub fn upcoming(rituals: &[Ritual], now: DateTime<Utc>, horizon_days: i64) -> ScheduleListing {
+    let horizon = now.naive_utc() + Duration::days(horizon_days);
+    let mut occurrences = Vec::new();
+
+    for ritual in rituals {
+        let Some(schedule) = &ritual.schedule else {
+            continue;
+        };
+        // Stored rituals were validated on write; a bad rule is skipped, not fatal
+        let Ok(rule) = Rule::parse(&schedule.rrule) else {
+            continue;
+        };
+
+        let duration = Duration::minutes(schedule.duration_minutes.into());
+        let from = now.naive_utc() - duration;
+        for start in rule.occurrences(schedule.start, from, horizon, MAX_PER_RITUAL) {
+            let start = start.and_utc();
+            if start + duration <= now {
+                continue;
+            }
+            occurrences.push(Occurrence {
+                ritual_id: ritual.id.clone(),
+                name: ritual.name.clone(),
+                start,
+                end: start + duration,
+                prepare_from: start - Duration::days(schedule.lead_time_days.into()),
+            });
+        }
+    }
+
+    occurrences.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| a.ritual_id.cmp(&b.ritual_id)));
+    ScheduleListing { computed_at: now, horizon_days, occurrences }
+}
+
+fn clamp_horizon(days: Option<i64>) -> i64 {
+    days.unwrap_or(DEFAULT_HORIZON_DAYS).clamp(1, MAX_HORIZON_DAYS)
+}
+
+// --- BACKGROUND SCHEDULER (serve mode) ---
+
+// Latest snapshot, shared between the scheduler task and GET /schedule
+pub type ScheduleState = Arc<RwLock<ScheduleListing>>;
+
+pub fn spawn(db: Arc<Database>) -> ScheduleState {
+    let state: ScheduleState = Arc::new(RwLock::new(ScheduleListing {
+        computed_at: Utc::now(),
+        horizon_days: MAX_HORIZON_DAYS,
+        occurrences: Vec::new(),
+    }));
+
+    let shared = state.clone();
+    tokio::spawn(async move {
+        // (ritual id, start, "lead" | "start") already printed
+        let mut announced: HashSet<(String, DateTime<Utc>, &'static str)> = HashSet::new();
+        let mut ticker = tokio::time::interval(std::time::Duration::from_secs(REFRESH_SECONDS));
+        loop {
+            ticker.tick().await;
+            let db = db.clone();
+            let computed = tokio::task::spawn_blocking(move || {
+                load_rituals(&db, &RitualFilter::default()).map(|r| upcoming(&r, Utc::now(), MAX_HORIZON_DAYS))
+            })
+            .await;
+
+            let listing = match computed {
+                Ok(Ok(listing)) => listing,
+                Ok(Err(e)) => {
+                    eprintln!("{} cannot load rituals: {}", "SCHEDULE".red().bold(), e);
+                    continue;
+                }
+                Err(e) => {
+                    eprintln!("{} scheduler task failed: {}", "SCHEDULE".red().bold(), e);
+                    continue;
+                }
+            };
+
+            announce(&listing, &mut announced);
+            if let Ok(mut snapshot) = shared.write() {
+                *snapshot = listing;
+            }
+        }
+    });
+    state
+}
+
+fn announce(listing: &ScheduleListing, announced: &mut HashSet<(String, DateTime<Utc>, &'static str)>) {
+    let now = listing.computed_at;
+    let tag = "SCHEDULE".magenta().bold();
+    for o in &listing.occurrences {
+        if o.in_lead_window(now) && announced.insert((o.ritual_id.clone(), o.start, "lead")) {
+            println!(
+                "{} {} {} starts {} (prepare now)",
+                tag,
+                o.ritual_id.cyan(),
+                o.name,
+                o.start.format("%Y-%m-%d %H:%M UTC")
+            );
+        }
+        if o.start <= now && announced.insert((o.ritual_id.clone(), o.start, "start")) {
+            println!(
+                "{} {} {} {} until {}",
+                tag,
+                o.ritual_id.cyan(),
+                o.name,
+                "is on now".green().bold(),
+                o.end.format("%Y-%m-%d %H:%M UTC")
+            );
+        }
+    }
+    // Forget announcements for occurrences that have ended
+    announced.retain(|(id, start, _)| listing.occurrences.iter().any(|o| o.ritual_id == *id && o.start == *start));
+}
+
+// --- CLI: `culture-kernel schedule` ---
+pub fn schedule_cli(db: &Arc<Database>, days: Option<i64>, ritual: Option<&str>) -> anyhow::Result<()> {
+    let rituals = load_rituals(db, &RitualFilter::default())?;
+    if let Some(id) = ritual {
+        if !rituals.iter().any(|r| r.id == id) {
+            return Err(KernelError::not_found("ritual", id).into());
+        }
+    }
+    let listing = upcoming(&rituals, Utc::now(), MAX_HORIZON_DAYS).narrowed(clamp_horizon(days), ritual);
+    print!("{}", render::ansi_schedule(&listing));
+    Ok(())
+}
+
+// --- API: GET /schedule ---
+
+#[derive(Debug, Deserialize)]
+pub struct ScheduleParams {
+    days: Option<i64>,
+    ritual: Option<String>,
+}
+
+// GET /schedule?days=30&ritual=RWANDA_21_UMUGANDA
+pub async fn api_schedule(
+    State(db): State<Arc<Database>>,
+    Extension(state): Extension<ScheduleState>,
+    Query(params): Query<ScheduleParams>,
+    Query(format): Query<FormatParam>,
+    headers: HeaderMap,
+) -> Response {
+    let format = match render::negotiate(&headers, format.format.as_deref()) {
+        Ok(f) => f,
+        Err(e) => return e.into_response(),
+    };
+
+    // Same answer as the CLI for an unknown ritual, even though it has no occurrences
+    if let Some(id) = params.ritual.as_deref() {
+        match load_ritual(&db, id) {
+            Ok(Some(_)) => {}
+            Ok(None) => return KernelError::not_found("ritual", id).respond(format),
+            Err(e) => return e.respond(format),
+        }
+    }
+
+    let listing = match state.read() {
+        Ok(snapshot) => snapshot.narrowed(clamp_horizon(params.days), params.ritual.as_deref()),
+        Err(_) => {
+            return KernelError::Internal("schedule snapshot lock is poisoned".to_string()).respond(format)
+        }
+    };
+    render::schedule_response(format, &listing)
+}
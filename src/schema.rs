@@ -0,0 +1,175 @@
+// --- SCHEMA VERSIONING ---
+// The schema version of stored rituals lives in META_TABLE. On startup every
+// record older than CURRENT_VERSION is upgraded, one migration step at a time,
+// inside a single write transaction.
+
+use colored::*;
+use redb::{Database, ReadableTable};
+use serde_json::Value;
+
+use crate::error::KernelError;
+use crate::{read_meta, META_TABLE, RITUALS_TABLE};
+
+pub const CURRENT_VERSION: u32 = 2;
+
+const VERSION_KEY: &str = "schema_version";
+
+// Databases created before versioning existed have no marker: they are v1
+const UNVERSIONED: u32 = 1;
+
+struct Migration {
+    // Version this step upgrades a record *to*
+    to: u32,
+    name: &'static str,
+    apply: fn(&mut Value) -> Result<(), String>,
+}
+
+const MIGRATIONS: &[Migration] = &[Migration {
+    to: 2,
+    name: "canonical field names and shapes",
+    apply: v1_to_v2,
+}];
+
+#[derive(Debug, Default)]
+pub struct MigrationReport {
+    pub from: u32,
+    pub to: u32,
+    pub migrated: usize,
+    // Records a step could not upgrade; left as-is for the quarantine to catch
+    pub failed: Vec<(String, String)>,
+}
+
+// v1 records were written by hand (and by the first README) with looser shapes:
+// `protocol_id` instead of `id`, a single guardrail string, numeric script values.
+fn v1_to_v2(record: &mut Value) -> Result<(), String> {
+    let obj = record.as_object_mut().ok_or("record is not a JSON object")?;
+
+    if !obj.contains_key("id") {
+        if let Some(id) = obj.remove("protocol_id") {
+            obj.insert("id".into(), id);
+        }
+    } else {
+        obj.remove("protocol_id");
+    }
+
+    match obj.get_mut("modern_script") {
+        None | Some(Value::Null) => {
+            obj.insert("modern_script".into(), Value::Object(Default::default()));
+        }
+        Some(Value::Object(script)) => {
+            for value in script.values_mut() {
+                let text = match &*value {
+                    Value::String(_) => continue,
+                    Value::Null => String::new(),
+                    other => other.to_string(),
+                };
+                *value = Value::String(text);
+            }
+        }
+        Some(_) => return Err("modern_script is not an object".into()),
+    }
+
+    match obj.get_mut("ethical_guardrails") {
+        None | Some(Value::Null) => {
+            obj.insert("ethical_guardrails".into(), Value::Array(Vec::new()));
+        }
+        Some(Value::String(single)) => {
+            let single = std::mem::take(single);
+            obj.insert("ethical_guardrails".into(), Value::Array(vec![Value::String(single)]));
+        }
+        Some(Value::Array(_)) => {}
+        Some(_) => return Err("ethical_guardrails is not a list".into()),
+    }
+
+    Ok(())
+}
+
+fn upgrade(raw: &str, from: u32) -> Result<String, String> {
+    let mut record: Value = serde_json::from_str(raw).map_err(|e| e.to_string())?;
+    for migration in MIGRATIONS.iter().filter(|m| m.to > from) {
+        (migration.apply)(&mut record).map_err(|e| format!("{}: {}", migration.name, e))?;
+    }
+    serde_json::to_string(&record).map_err(|e| e.to_string())
+}
+
+pub fn stored_version(db: &Database) -> Result<Option<u32>, KernelError> {
+    Ok(read_meta(db, VERSION_KEY)?.and_then(|v| v.parse().ok()))
+}
+
+// Brings the database to CURRENT_VERSION. Safe to call on every startup.
+pub fn migrate(db: &Database) -> Result<MigrationReport, KernelError> {
+    let from = stored_version(db)?.unwrap_or(UNVERSIONED);
+    let mut report = MigrationReport { from, to: CURRENT_VERSION, ..Default::default() };
+
+    if from > CURRENT_VERSION {
+        return Err(KernelError::Validation(vec![format!(
+            "database schema v{} is newer than this kernel (v{}); upgrade the binary",
+            from, CURRENT_VERSION
+        )]));
+    }
+    if from == CURRENT_VERSION {
+        return Ok(report);
+    }
+
+    // Read first so a fresh database is left untouched; the startup heal pass
+    // restores its rituals from the seed manifest.
+    let records: Vec<(String, String)> = {
+        let read_txn = db.begin_read()?;
+        let records = match read_txn.open_table(RITUALS_TABLE) {
+            Ok(table) => table
+                .iter()?
+                .map(|item| item.map(|(k, v)| (k.value().to_string(), v.value().to_string())))
+                .collect::<Result<_, _>>()?,
+            Err(redb::TableError::TableDoesNotExist(_)) => Vec::new(),
+            Err(e) => return Err(e.into()),
+        };
+        records
+    };
+    let had_records = !records.is_empty();
+
+    let write_txn = db.begin_write()?;
+    {
+        let mut upgraded_records = Vec::new();
+        for (id, raw) in records {
+            match upgrade(&raw, from) {
+                Ok(upgraded) if upgraded != raw => upgraded_records.push((id, upgraded)),
+                Ok(_) => {}
+                Err(e) => report.failed.push((id, e)),
+            }
+        }
+
+        if !upgraded_records.is_empty() {
+            let mut rituals = write_txn.open_table(RITUALS_TABLE)?;
+            for (id, upgraded) in &upgraded_records {
+                rituals.insert(id.as_str(), upgraded.as_str())?;
+            }
+            report.migrated = upgraded_records.len();
+        }
+
+        let mut meta = write_txn.open_table(META_TABLE)?;
+        meta.insert(VERSION_KEY, CURRENT_VERSION.to_string().as_str())?;
+    }
+    write_txn.commit()?;
+
+    if had_records {
+        println!(
+            "{} schema v{} -> v{}: {} record(s) upgraded",
+            "MIGRATION".yellow().bold(),
+            report.from,
+            report.to,
+            report.migrated
+        );
+        for (id, error) in &report.failed {
+            println!("  {} {} ({})", "could not upgrade".red(), id.cyan(), error);
+        }
+        if !report.failed.is_empty() {
+            println!("  Run 'culture-kernel doctor' to quarantine them.");
+        }
+    }
+    Ok(report)
+}
+
+// Quarantined raw JSON predates the migration, so upgrade it too before a restore
+pub fn upgrade_quarantined(raw: &str) -> String {
+    upgrade(raw, UNVERSIONED).unwrap_or_else(|_| raw.to_string())
+}
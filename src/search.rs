@@ -0,0 +1,350 @@
+// --- FULL-TEXT SEARCH ---
+// An inverted index over the searchable text of every ritual, kept in redb next to
+// the secondary indexes and maintained by the same `index::put_ritual` / `remove_ritual`
+// calls. Queries are ranked with BM25 and come back with highlighted snippets.
+
+use axum::{
+    extract::{Query, State},
+    http::HeaderMap,
+    response::{IntoResponse, Response},
+};
+use redb::{Database, ReadableTable, TableDefinition, WriteTransaction};
+use serde::{ser::SerializeStruct, Deserialize, Serialize, Serializer};
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+use crate::error::KernelError;
+use crate::render::{self, escape_html, FormatParam};
+use crate::{quarantine, Ritual, RITUALS_TABLE};
+
+// (term, ritual id) -> term frequency in that ritual
+const POSTINGS_TABLE: TableDefinition<(&str, &str), u32> = TableDefinition::new("search_postings");
+// ritual id -> number of indexed terms (document length for BM25)
+const DOCUMENTS_TABLE: TableDefinition<&str, u32> = TableDefinition::new("search_documents");
+
+// Standard BM25 parameters
+const K1: f64 = 1.2;
+const B: f64 = 0.75;
+
+const DEFAULT_LIMIT: usize = 10;
+const MAX_LIMIT: usize = 50;
+const SNIPPETS_PER_HIT: usize = 2;
+// Bytes of context kept before a match (twice as much after it)
+const SNIPPET_CONTEXT: usize = 60;
+
+const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "for", "from", "in", "is", "it", "of", "on",
+    "or", "the", "to", "with",
+];
+
+// A word in some text: byte range plus the normalized term
+struct Token {
+    start: usize,
+    end: usize,
+    term: String,
+}
+
+fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices().chain(std::iter::once((text.len(), ' '))) {
+        match (c.is_alphanumeric(), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                let term = text[s..i].to_lowercase();
+                if term.chars().count() >= 2 && !STOPWORDS.contains(&term.as_str()) {
+                    tokens.push(Token { start: s, end: i, term });
+                }
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    tokens
+}
+
+// The indexable words of a text, in their original spelling
+pub fn words(text: &str) -> Vec<&str> {
+    tokenize(text).into_iter().map(|t| &text[t.start..t.end]).collect()
+}
+
+// Searchable fields in snippet priority order, labelled the way clients address them
+fn fields(ritual: &Ritual) -> Vec<(String, &str)> {
+    let mut fields = vec![
+        ("name".to_string(), ritual.name.as_str()),
+        ("bug_fixed".to_string(), ritual.bug_fixed.as_str()),
+        ("mechanism".to_string(), ritual.mechanism.as_str()),
+        ("category".to_string(), ritual.category.as_str()),
+    ];
+    let mut steps: Vec<_> = ritual.modern_script.iter().collect();
+    steps.sort();
+    for (key, value) in steps {
+        fields.push((format!("modern_script.{}", key), value.as_str()));
+    }
+    for (i, guardrail) in ritual.ethical_guardrails.iter().enumerate() {
+        fields.push((format!("ethical_guardrails[{}]", i), guardrail.as_str()));
+    }
+    fields
+}
+
+fn term_frequencies(ritual: &Ritual) -> (BTreeMap<String, u32>, u32) {
+    let mut frequencies = BTreeMap::new();
+    let mut length = 0;
+    for (_, text) in fields(ritual) {
+        for token in tokenize(text) {
+            *frequencies.entry(token.term).or_insert(0) += 1;
+            length += 1;
+        }
+    }
+    (frequencies, length)
+}
+
+// --- INDEX MAINTENANCE (called from index.rs) ---
+
+pub fn add_document(txn: &WriteTransaction, ritual: &Ritual) -> Result<(), KernelError> {
+    let (frequencies, length) = term_frequencies(ritual);
+    let mut postings = txn.open_table(POSTINGS_TABLE)?;
+    for (term, tf) in &frequencies {
+        postings.insert((term.as_str(), ritual.id.as_str()), *tf)?;
+    }
+    let mut documents = txn.open_table(DOCUMENTS_TABLE)?;
+    documents.insert(ritual.id.as_str(), length)?;
+    Ok(())
+}
+
+pub fn remove_document(txn: &WriteTransaction, ritual: &Ritual) -> Result<(), KernelError> {
+    let (frequencies, _) = term_frequencies(ritual);
+    let mut postings = txn.open_table(POSTINGS_TABLE)?;
+    for term in frequencies.keys() {
+        postings.remove((term.as_str(), ritual.id.as_str()))?;
+    }
+    let mut documents = txn.open_table(DOCUMENTS_TABLE)?;
+    documents.remove(ritual.id.as_str())?;
+    Ok(())
+}
+
+pub fn clear(txn: &WriteTransaction) -> Result<(), KernelError> {
+    txn.delete_table(POSTINGS_TABLE)?;
+    txn.delete_table(DOCUMENTS_TABLE)?;
+    Ok(())
+}
+
+// --- QUERYING ---
+
+#[derive(Debug, Serialize)]
+pub struct SearchResults {
+    pub query: String,
+    pub total: usize,
+    pub hits: Vec<SearchHit>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SearchHit {
+    pub id: String,
+    pub name: String,
+    pub origin_culture: String,
+    pub category: String,
+    pub score: f64,
+    pub snippets: Vec<Snippet>,
+}
+
+// An excerpt of one field; `parts` alternate between plain text and matched terms
+#[derive(Debug)]
+pub struct Snippet {
+    pub field: String,
+    parts: Vec<(String, bool)>,
+}
+
+impl Snippet {
+    // Renders the excerpt, passing matched terms through `mark` and the rest through `plain`
+    pub fn highlight(&self, mark: impl Fn(&str) -> String, plain: impl Fn(&str) -> String) -> String {
+        self.parts
+            .iter()
+            .map(|(text, hit)| if *hit { mark(text) } else { plain(text) })
+            .collect()
+    }
+}
+
+// JSON carries the excerpt as an HTML fragment: matches in <mark>, everything else escaped
+impl Serialize for Snippet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let text = self.highlight(|t| format!("<mark>{}</mark>", escape_html(t)), escape_html);
+        let mut state = serializer.serialize_struct("Snippet", 2)?;
+        state.serialize_field("field", &self.field)?;
+        state.serialize_field("text", &text)?;
+        state.end()
+    }
+}
+
+pub fn search(db: &Database, query: &str, limit: usize) -> Result<SearchResults, KernelError> {
+    let terms: BTreeSet<String> = tokenize(query).into_iter().map(|t| t.term).collect();
+    if terms.is_empty() {
+        return Err(KernelError::Validation(vec![
+            "q must contain at least one searchable word".to_string(),
+        ]));
+    }
+
+    let read_txn = db.begin_read()?;
+    let (postings, documents) = match (read_txn.open_table(POSTINGS_TABLE), read_txn.open_table(DOCUMENTS_TABLE)) {
+        (Ok(p), Ok(d)) => (p, d),
+        (Err(redb::TableError::TableDoesNotExist(_)), _) | (_, Err(redb::TableError::TableDoesNotExist(_))) => {
+            return Ok(SearchResults { query: query.to_string(), total: 0, hits: Vec::new() });
+        }
+        (Err(e), _) | (_, Err(e)) => return Err(e.into()),
+    };
+
+    let mut lengths: BTreeMap<String, u32> = BTreeMap::new();
+    for item in documents.iter()? {
+        let (id, length) = item?;
+        lengths.insert(id.value().to_string(), length.value());
+    }
+    let total_docs = lengths.len() as f64;
+    let average_length = lengths.values().map(|&l| l as f64).sum::<f64>() / total_docs.max(1.0);
+
+    let mut scores: BTreeMap<String, f64> = BTreeMap::new();
+    for term in &terms {
+        let mut matches = Vec::new();
+        for item in postings.range((term.as_str(), "")..)? {
+            let (key, tf) = item?;
+            let (key_term, id) = key.value();
+            if key_term != term {
+                break;
+            }
+            matches.push((id.to_string(), tf.value() as f64));
+        }
+
+        let df = matches.len() as f64;
+        let idf = (1.0 + (total_docs - df + 0.5) / (df + 0.5)).ln();
+        for (id, tf) in matches {
+            let length = lengths.get(&id).copied().unwrap_or(0) as f64;
+            let norm = tf * (K1 + 1.0) / (tf + K1 * (1.0 - B + B * length / average_length.max(1.0)));
+            *scores.entry(id).or_insert(0.0) += idf * norm;
+        }
+    }
+
+    let mut ranked: Vec<(String, f64)> = scores.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+    // `total` only counts rituals that actually load, and skipped ids let later
+    // candidates fill the page
+    let rituals = read_txn.open_table(RITUALS_TABLE)?;
+    let mut total = 0;
+    let mut hits = Vec::new();
+    for (id, score) in ranked {
+        let Some(raw) = rituals.get(id.as_str())? else {
+            continue;
+        };
+        // Corrupt rows are the quarantine's business; search just skips them
+        let Ok(ritual) = quarantine::parse_stored(&id, raw.value()) else {
+            continue;
+        };
+        total += 1;
+        if hits.len() >= limit {
+            continue;
+        }
+        hits.push(SearchHit {
+            snippets: snippets(&ritual, &terms),
+            score: (score * 1000.0).round() / 1000.0,
+            id: ritual.id,
+            name: ritual.name,
+            origin_culture: ritual.origin_culture,
+            category: ritual.category,
+        });
+    }
+
+    Ok(SearchResults { query: query.to_string(), total, hits })
+}
+
+// The fields with the most distinct matching terms, each cut down to a window around its first match
+fn snippets(ritual: &Ritual, terms: &BTreeSet<String>) -> Vec<Snippet> {
+    let mut candidates: Vec<(usize, Snippet)> = Vec::new();
+    for (field, text) in fields(ritual) {
+        let matched: Vec<Token> = tokenize(text).into_iter().filter(|t| terms.contains(&t.term)).collect();
+        let Some(first) = matched.first() else {
+            continue;
+        };
+        let distinct = matched.iter().map(|t| t.term.as_str()).collect::<BTreeSet<_>>().len();
+
+        let (lo, hi) = window(text, first.start, first.end);
+        let mut parts = Vec::new();
+        let mut cursor = lo;
+        if lo > 0 {
+            parts.push(("…".to_string(), false));
+        }
+        for token in matched.iter().filter(|t| t.start >= lo && t.end <= hi) {
+            if token.start > cursor {
+                parts.push((text[cursor..token.start].to_string(), false));
+            }
+            parts.push((text[token.start..token.end].to_string(), true));
+            cursor = token.end;
+        }
+        if cursor < hi {
+            parts.push((text[cursor..hi].to_string(), false));
+        }
+        if hi < text.len() {
+            parts.push(("…".to_string(), false));
+        }
+        candidates.push((distinct, Snippet { field, parts }));
+    }
+
+    // Stable sort keeps field priority among equally good snippets
+    candidates.sort_by_key(|c| std::cmp::Reverse(c.0));
+    candidates.into_iter().take(SNIPPETS_PER_HIT).map(|(_, s)| s).collect()
+}
+
+// Byte range around a match, widened by SNIPPET_CONTEXT and trimmed to whole words
+fn window(text: &str, start: usize, end: usize) -> (usize, usize) {
+    let mut lo = start.saturating_sub(SNIPPET_CONTEXT);
+    while !text.is_char_boundary(lo) {
+        lo -= 1;
+    }
+    if lo > 0 {
+        lo = text[lo..start].find(' ').map(|i| lo + i + 1).unwrap_or(lo);
+    }
+
+    let mut hi = (end + SNIPPET_CONTEXT * 2).min(text.len());
+    while !text.is_char_boundary(hi) {
+        hi += 1;
+    }
+    if hi < text.len() {
+        hi = text[end..hi].rfind(' ').map(|i| end + i).unwrap_or(hi);
+    }
+    (lo, hi)
+}
+
+fn clamp_limit(limit: Option<usize>) -> usize {
+    limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT)
+}
+
+// --- CLI: `culture-kernel search` ---
+pub fn search_cli(db: &Arc<Database>, query: &str, limit: Option<usize>) -> anyhow::Result<()> {
+    let results = search(db, query, clamp_limit(limit))?;
+    print!("{}", render::ansi_search(&results));
+    Ok(())
+}
+
+// --- API: GET /search ---
+
+#[derive(Debug, Deserialize)]
+pub struct SearchParams {
+    q: Option<String>,
+    limit: Option<usize>,
+}
+
+// GET /search?q=...&limit=...
+pub async fn api_search(
+    State(db): State<Arc<Database>>,
+    Query(params): Query<SearchParams>,
+    Query(format): Query<FormatParam>,
+    headers: HeaderMap,
+) -> Response {
+    let format = match render::negotiate(&headers, format.format.as_deref()) {
+        Ok(f) => f,
+        Err(e) => return e.into_response(),
+    };
+
+    match search(&db, params.q.as_deref().unwrap_or_default(), clamp_limit(params.limit)) {
+        Ok(results) => render::search_response(format, &results),
+        Err(e) => e.respond(format),
+    }
+}
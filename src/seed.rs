@@ -0,0 +1,325 @@
+// --- SEEDING ---
+// Loads the ritual library (seed files or pack directories, else the embedded copy) into RITUALS_TABLE.
+// Every run is planned as a diff first, so `--dry-run` shows exactly what a real
+// run would write, and a content hash in META_TABLE turns unchanged seeds into no-ops.
+
+use clap::ValueEnum;
+use colored::*;
+use redb::{Database, ReadableTable};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use crate::error::KernelError;
+use crate::quarantine::{self, CorruptRecord};
+use crate::{index, read_meta, Ritual, META_TABLE, RITUALS_TABLE};
+
+// Picked up from the working directory when no --seed-file is configured
+pub const DEFAULT_SEED_FILE: &str = "rituals.json";
+
+// The canonical library, compiled in so a kernel can always seed itself
+pub const EMBEDDED_LIBRARY: &str = include_str!("../rituals.json");
+
+const SEED_HASH_KEY: &str = "seed_hash";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SeedMode {
+    /// Add new rituals and overwrite changed ones; keep rituals missing from the seed
+    Upsert,
+    /// Make the table match the seed exactly, removing rituals missing from it
+    Replace,
+    /// Only add rituals whose id is not stored yet; never touch existing ones
+    OnlyMissing,
+}
+
+impl SeedMode {
+    fn as_str(self) -> &'static str {
+        match self {
+            SeedMode::Upsert => "upsert",
+            SeedMode::Replace => "replace",
+            SeedMode::OnlyMissing => "only-missing",
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct SeedPlan {
+    added: Vec<Ritual>,
+    // (stored, incoming)
+    changed: Vec<(Ritual, Ritual)>,
+    removed: Vec<Ritual>,
+    unchanged: usize,
+    // Stored rows that no longer parse; a real run quarantines them before writing
+    corrupt: Vec<CorruptRecord>,
+}
+
+impl SeedPlan {
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.changed.is_empty() && self.removed.is_empty() && self.corrupt.is_empty()
+    }
+}
+
+pub enum SeedOutcome {
+    // Seed content and mode match the last applied run
+    Unchanged,
+    DryRun(SeedPlan),
+    Applied(SeedPlan),
+}
+
+// Builds the seed from the configured sources, later ones overriding earlier ones by id.
+// With no configured sources, ./rituals.json is used if it exists. A directory is a pack
+// folder: all of its `*.json` files are loaded alphabetically. The embedded library is
+// only the fallback when there is no source at all, so a ritual removed from the seed
+// files stays removed (replace mode, startup repair).
+pub fn read_seed_sources(paths: &[PathBuf]) -> anyhow::Result<Vec<Ritual>> {
+    let default_source = [PathBuf::from(DEFAULT_SEED_FILE)];
+    let paths = match paths {
+        [] if default_source[0].is_file() => &default_source[..],
+        [] => return embedded_library(),
+        other => other,
+    };
+
+    let mut merged: BTreeMap<String, Ritual> = BTreeMap::new();
+    for path in paths {
+        for file in expand_source(path)? {
+            for ritual in read_seed_file(&file)? {
+                let overrides = match merged.get(&ritual.id) {
+                    Some(existing) => serde_json::to_value(existing)? != serde_json::to_value(&ritual)?,
+                    None => false,
+                };
+                if overrides {
+                    println!(
+                        "{} {} overridden by {}",
+                        "SEED".yellow().bold(),
+                        ritual.id.cyan(),
+                        file.display()
+                    );
+                }
+                merged.insert(ritual.id.clone(), ritual);
+            }
+        }
+    }
+    Ok(merged.into_values().collect())
+}
+
+pub fn embedded_library() -> anyhow::Result<Vec<Ritual>> {
+    serde_json::from_str(EMBEDDED_LIBRARY)
+        .map_err(|e| anyhow::anyhow!("embedded ritual library is corrupt: {}", e))
+}
+
+// `culture-kernel export-default`: writes the embedded library out as a starting pack
+pub fn export_default_cli(output: Option<&Path>, force: bool) -> anyhow::Result<()> {
+    let Some(path) = output else {
+        print!("{}", EMBEDDED_LIBRARY);
+        return Ok(());
+    };
+
+    if path.exists() && !force {
+        anyhow::bail!("'{}' already exists (use --force to overwrite)", path.display());
+    }
+    std::fs::write(path, EMBEDDED_LIBRARY)
+        .map_err(|e| anyhow::anyhow!("cannot write '{}': {}", path.display(), e))?;
+    println!(
+        "{} {} rituals to {}",
+        "Exported".green().bold(),
+        embedded_library()?.len(),
+        path.display()
+    );
+    Ok(())
+}
+
+fn expand_source(path: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    if !path.is_dir() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+
+    let mut files: Vec<PathBuf> = std::fs::read_dir(path)
+        .map_err(|e| anyhow::anyhow!("cannot read seed directory '{}': {}", path.display(), e))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|p| p.is_file() && p.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    files.sort();
+
+    if files.is_empty() {
+        anyhow::bail!("seed directory '{}' contains no .json packs", path.display());
+    }
+    Ok(files)
+}
+
+fn read_seed_file(path: &Path) -> anyhow::Result<Vec<Ritual>> {
+    let data = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("cannot read seed file '{}': {}", path.display(), e))?;
+    let rituals: Vec<Ritual> = serde_json::from_str(&data)
+        .map_err(|e| anyhow::anyhow!("seed file '{}' is not a valid ritual list: {}", path.display(), e))?;
+
+    let mut problems = Vec::new();
+    for ritual in &rituals {
+        for problem in ritual.validate() {
+            problems.push(format!("{} ({}): {}", ritual.id, path.display(), problem));
+        }
+    }
+    if !problems.is_empty() {
+        return Err(KernelError::Validation(problems).into());
+    }
+    Ok(rituals)
+}
+
+// Hash of the canonical seed content plus the mode, independent of key order and formatting
+fn seed_hash(rituals: &[Ritual], mode: SeedMode) -> Result<String, KernelError> {
+    let canonical: BTreeMap<&str, Value> = rituals
+        .iter()
+        .map(|r| serde_json::to_value(r).map(|v| (r.id.as_str(), v)))
+        .collect::<Result<_, _>>()?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(mode.as_str().as_bytes());
+    hasher.update(serde_json::to_vec(&canonical)?);
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect())
+}
+
+// Read-only: corrupt rows are reported in the plan, never quarantined from here
+pub fn plan(db: &Database, seed: Vec<Ritual>, mode: SeedMode) -> Result<SeedPlan, KernelError> {
+    let mut plan = SeedPlan::default();
+    let mut stored: BTreeMap<String, Ritual> = BTreeMap::new();
+    {
+        let read_txn = db.begin_read()?;
+        match read_txn.open_table(RITUALS_TABLE) {
+            Ok(table) => {
+                for item in table.iter()? {
+                    let (key, value) = item?;
+                    let (id, raw) = (key.value(), value.value());
+                    match quarantine::parse_stored(id, raw) {
+                        Ok(ritual) => {
+                            stored.insert(id.to_string(), ritual);
+                        }
+                        Err(error) => plan.corrupt.push(CorruptRecord { id: id.to_string(), raw: raw.to_string(), error }),
+                    }
+                }
+            }
+            Err(redb::TableError::TableDoesNotExist(_)) => {}
+            Err(e) => return Err(e.into()),
+        };
+    }
+
+    for incoming in seed {
+        match stored.remove(&incoming.id) {
+            None => plan.added.push(incoming),
+            Some(current) => {
+                let same = serde_json::to_value(&current)? == serde_json::to_value(&incoming)?;
+                if same || mode == SeedMode::OnlyMissing {
+                    plan.unchanged += 1;
+                } else {
+                    plan.changed.push((current, incoming));
+                }
+            }
+        }
+    }
+
+    if mode == SeedMode::Replace {
+        plan.removed = stored.into_values().collect();
+    }
+    Ok(plan)
+}
+
+fn apply(db: &Database, plan: &SeedPlan, hash: &str) -> Result<(), KernelError> {
+    let write_txn = db.begin_write()?;
+    {
+        let writes = plan.added.iter().chain(plan.changed.iter().map(|(_, new)| new));
+        for ritual in writes {
+            index::put_ritual(&write_txn, ritual)?;
+        }
+        for ritual in &plan.removed {
+            index::remove_ritual(&write_txn, &ritual.id)?;
+        }
+
+        let mut meta = write_txn.open_table(META_TABLE)?;
+        meta.insert(SEED_HASH_KEY, hash)?;
+    }
+    write_txn.commit()?;
+    Ok(())
+}
+
+pub fn run(db: &Database, sources: &[PathBuf], mode: SeedMode, dry_run: bool, force: bool) -> anyhow::Result<SeedOutcome> {
+    let seed = read_seed_sources(sources)?;
+    let hash = seed_hash(&seed, mode)?;
+
+    if !force && !dry_run && read_meta(db, SEED_HASH_KEY)?.as_deref() == Some(hash.as_str()) {
+        return Ok(SeedOutcome::Unchanged);
+    }
+
+    let plan = plan(db, seed, mode)?;
+    if dry_run {
+        return Ok(SeedOutcome::DryRun(plan));
+    }
+
+    quarantine::quarantine(db, plan.corrupt.clone())?;
+    apply(db, &plan, &hash)?;
+    Ok(SeedOutcome::Applied(plan))
+}
+
+// --- CLI OUTPUT ---
+
+pub fn print_outcome(outcome: &SeedOutcome, mode: SeedMode) {
+    let plan = match outcome {
+        SeedOutcome::Unchanged => {
+            println!("{}", "Seed unchanged since last run. Nothing to do (use --force to re-apply).".green());
+            return;
+        }
+        SeedOutcome::DryRun(plan) => {
+            println!("{}", format!(" SEED DRY RUN ({}) ", mode.as_str()).on_blue().white().bold());
+            plan
+        }
+        SeedOutcome::Applied(plan) => plan,
+    };
+
+    for ritual in &plan.added {
+        println!("{} {} {}", "+ ADDED  ".green().bold(), ritual.id.cyan(), ritual.name);
+    }
+    for (current, incoming) in &plan.changed {
+        println!(
+            "{} {} ({})",
+            "~ CHANGED".yellow().bold(),
+            incoming.id.cyan(),
+            changed_fields(current, incoming).join(", ")
+        );
+    }
+    for ritual in &plan.removed {
+        println!("{} {} {}", "- REMOVED".red().bold(), ritual.id.cyan(), ritual.name);
+    }
+    for record in &plan.corrupt {
+        println!("{} {} ({})", "! CORRUPT".red().bold(), record.id.cyan(), record.error);
+    }
+
+    let summary = format!(
+        "{} added, {} changed, {} removed, {} unchanged, {} corrupt",
+        plan.added.len(),
+        plan.changed.len(),
+        plan.removed.len(),
+        plan.unchanged,
+        plan.corrupt.len()
+    );
+    match outcome {
+        SeedOutcome::DryRun(_) => println!("{} (dry run, nothing written)", summary),
+        _ if plan.is_empty() => println!("{}", "Database already matches the seed.".green().bold()),
+        _ => println!("{} {}", "Database seeded successfully:".green().bold(), summary),
+    }
+}
+
+fn changed_fields(current: &Ritual, incoming: &Ritual) -> Vec<String> {
+    let (Ok(Value::Object(a)), Ok(Value::Object(b))) =
+        (serde_json::to_value(current), serde_json::to_value(incoming))
+    else {
+        return Vec::new();
+    };
+    // Keys from both sides, so a field dropped from the seed shows up too
+    let keys: BTreeSet<&String> = a.keys().chain(b.keys()).collect();
+    keys.into_iter()
+        .filter(|key| a.get(*key) != b.get(*key))
+        .cloned()
+        .collect()
+}
@@ -0,0 +1,369 @@
+// --- SESSION LOG ---
+// Each time a team performs a ritual (a Pulaaku incident call, an Imihigo review...)
+// the performance is logged in SESSIONS_TABLE with its date, participants, notes and
+// outcome. Sessions are numbered in insertion order.
+
+use axum::{
+    extract::{rejection::JsonRejection, Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use clap::Subcommand;
+use colored::*;
+use redb::{Database, ReadableTable, TableDefinition};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::error::KernelError;
+use crate::{load_ritual, teams, META_TABLE};
+
+// session id -> Session JSON
+const SESSIONS_TABLE: TableDefinition<u64, &str> = TableDefinition::new("sessions");
+
+// Last id handed out, kept in META_TABLE so ids of deleted sessions are never reused
+const SESSION_SEQ_KEY: &str = "session_seq";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Session {
+    id: u64,
+    ritual_id: String,
+    team: Option<String>,
+    date: NaiveDate,
+    participants: Vec<String>,
+    notes: String,
+    outcome: String,
+    logged_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NewSession {
+    ritual_id: String,
+    team: Option<String>,
+    // Defaults to today
+    date: Option<NaiveDate>,
+    #[serde(default)]
+    participants: Vec<String>,
+    #[serde(default)]
+    notes: String,
+    #[serde(default)]
+    outcome: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct SessionFilter {
+    ritual: Option<String>,
+    team: Option<String>,
+}
+
+impl SessionFilter {
+    fn matches(&self, session: &Session) -> bool {
+        self.ritual.as_deref().is_none_or(|r| session.ritual_id == r)
+            && self.team.as_deref().is_none_or(|t| session.team.as_deref() == Some(t))
+    }
+}
+
+// Aggregates per ritual, shown in the terminal listing
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct SessionStats {
+    pub count: usize,
+    pub last: Option<NaiveDate>,
+    pub teams: usize,
+}
+
+pub fn log(db: &Database, new: NewSession) -> Result<Session, KernelError> {
+    if load_ritual(db, &new.ritual_id)?.is_none() {
+        return Err(KernelError::not_found("ritual", &new.ritual_id));
+    }
+    if let Some(team) = &new.team {
+        teams::require_team(db, team)?;
+    }
+
+    let today = Utc::now().date_naive();
+    let date = new.date.unwrap_or(today);
+    let mut problems = Vec::new();
+    if date > today {
+        problems.push(format!("date {} is in the future; log sessions after they happen", date));
+    }
+    if new.participants.iter().any(|p| p.trim().is_empty()) {
+        problems.push("participants must not contain empty names".to_string());
+    }
+    if !problems.is_empty() {
+        return Err(KernelError::Validation(problems));
+    }
+
+    let write_txn = db.begin_write()?;
+    let session = {
+        let mut meta = write_txn.open_table(META_TABLE)?;
+        let last: u64 = match meta.get(SESSION_SEQ_KEY)? {
+            Some(v) => v.value().parse().unwrap_or(0),
+            None => 0,
+        };
+        let mut table = write_txn.open_table(SESSIONS_TABLE)?;
+        let highest = table.last()?.map(|(key, _)| key.value()).unwrap_or(0);
+        let id = last.max(highest) + 1;
+        meta.insert(SESSION_SEQ_KEY, id.to_string().as_str())?;
+
+        let session = Session {
+            id,
+            ritual_id: new.ritual_id,
+            team: new.team,
+            date,
+            participants: new.participants.iter().map(|p| p.trim().to_string()).collect(),
+            notes: new.notes,
+            outcome: new.outcome,
+            logged_at: Utc::now(),
+        };
+        let json = serde_json::to_string(&session)?;
+        table.insert(id, json.as_str())?;
+        session
+    };
+    write_txn.commit()?;
+    Ok(session)
+}
+
+// Matching sessions, most recent performance first
+pub fn list(db: &Database, filter: &SessionFilter) -> Result<Vec<Session>, KernelError> {
+    let read_txn = db.begin_read()?;
+    let table = match read_txn.open_table(SESSIONS_TABLE) {
+        Ok(t) => t,
+        Err(redb::TableError::TableDoesNotExist(_)) => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut sessions = Vec::new();
+    for item in table.iter()? {
+        let (_, value) = item?;
+        let session: Session = serde_json::from_str(value.value())?;
+        if filter.matches(&session) {
+            sessions.push(session);
+        }
+    }
+    sessions.sort_by(|a, b| b.date.cmp(&a.date).then(b.id.cmp(&a.id)));
+    Ok(sessions)
+}
+
+pub fn get(db: &Database, id: u64) -> Result<Option<Session>, KernelError> {
+    let read_txn = db.begin_read()?;
+    let table = match read_txn.open_table(SESSIONS_TABLE) {
+        Ok(t) => t,
+        Err(redb::TableError::TableDoesNotExist(_)) => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let session = match table.get(id)? {
+        Some(v) => Some(serde_json::from_str(v.value())?),
+        None => None,
+    };
+    Ok(session)
+}
+
+pub fn remove(db: &Database, id: u64) -> Result<Option<Session>, KernelError> {
+    let write_txn = db.begin_write()?;
+    let removed = {
+        let mut table = write_txn.open_table(SESSIONS_TABLE)?;
+        let removed = match table.remove(id)? {
+            Some(v) => Some(serde_json::from_str(v.value())?),
+            None => None,
+        };
+        removed
+    };
+    write_txn.commit()?;
+    Ok(removed)
+}
+
+fn aggregate(sessions: Vec<Session>) -> BTreeMap<String, SessionStats> {
+    let mut teams: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    let mut stats: BTreeMap<String, SessionStats> = BTreeMap::new();
+    for session in sessions {
+        let entry = stats.entry(session.ritual_id.clone()).or_default();
+        entry.count += 1;
+        entry.last = entry.last.max(Some(session.date));
+
+        let seen = teams.entry(session.ritual_id).or_default();
+        if let Some(team) = session.team.filter(|t| !seen.contains(t)) {
+            seen.push(team);
+            entry.teams += 1;
+        }
+    }
+    stats
+}
+
+// Per-ritual aggregates for listings
+pub fn stats(db: &Database) -> Result<BTreeMap<String, SessionStats>, KernelError> {
+    Ok(aggregate(list(db, &SessionFilter::default())?))
+}
+
+// Stats are decoration on a ritual listing, so a sessions table that cannot be read
+// shows as "none logged" instead of failing the listing
+pub fn listing_stats(db: &Database) -> BTreeMap<String, SessionStats> {
+    stats(db).unwrap_or_default()
+}
+
+// Aggregates of one ritual only, for single-ritual lookups
+pub fn stats_for(db: &Database, ritual_id: &str) -> Result<Option<SessionStats>, KernelError> {
+    let filter = SessionFilter { ritual: Some(ritual_id.to_string()), team: None };
+    Ok(aggregate(list(db, &filter)?).remove(ritual_id))
+}
+
+// "3 sessions, last 2026-10-12, 2 teams" for terminal output
+pub fn describe(stats: Option<&SessionStats>) -> String {
+    match stats {
+        None => "none logged".to_string(),
+        Some(s) => format!(
+            "{} session{}{}{}",
+            s.count,
+            if s.count == 1 { "" } else { "s" },
+            s.last.map(|d| format!(", last {}", d)).unwrap_or_default(),
+            match s.teams {
+                0 => String::new(),
+                1 => ", 1 team".to_string(),
+                n => format!(", {} teams", n),
+            }
+        ),
+    }
+}
+
+// --- CLI: `culture-kernel sessions` ---
+
+#[derive(Subcommand, Debug)]
+pub enum SessionsCommand {
+    /// Record that a ritual was performed
+    Log {
+        /// Ritual id, e.g. HAUSA_08_PULAAKU
+        ritual: String,
+        /// Team that performed it
+        #[arg(long)]
+        team: Option<String>,
+        /// Date of the session (YYYY-MM-DD, defaults to today)
+        #[arg(long)]
+        date: Option<NaiveDate>,
+        /// Participant names (repeat the flag or separate with commas)
+        #[arg(short, long = "participant", value_delimiter = ',')]
+        participants: Vec<String>,
+        #[arg(long, default_value = "")]
+        notes: String,
+        /// What came out of it, e.g. "root cause found, 2 follow-ups"
+        #[arg(long, default_value = "")]
+        outcome: String,
+    },
+    /// List logged sessions, newest first
+    List {
+        #[arg(long)]
+        ritual: Option<String>,
+        #[arg(long)]
+        team: Option<String>,
+    },
+    /// Delete a session logged by mistake
+    Remove { id: u64 },
+}
+
+pub fn sessions_cli(db: &Arc<Database>, command: &SessionsCommand) -> anyhow::Result<()> {
+    match command {
+        SessionsCommand::Log { ritual, team, date, participants, notes, outcome } => {
+            let session = log(
+                db,
+                NewSession {
+                    ritual_id: ritual.clone(),
+                    team: team.clone(),
+                    date: *date,
+                    participants: participants.clone(),
+                    notes: notes.clone(),
+                    outcome: outcome.clone(),
+                },
+            )?;
+            println!(
+                "{} session #{} of {} on {}",
+                "Logged".green().bold(),
+                session.id,
+                session.ritual_id.cyan(),
+                session.date
+            );
+        }
+        SessionsCommand::List { ritual, team } => {
+            let filter = SessionFilter { ritual: ritual.clone(), team: team.clone() };
+            let sessions = list(db, &filter)?;
+            if sessions.is_empty() {
+                println!("No sessions logged yet.");
+            }
+            for s in sessions {
+                println!(
+                    "#{:<4} {} {} {}",
+                    s.id,
+                    s.date,
+                    s.ritual_id.cyan(),
+                    s.team.as_deref().map(|t| format!("({})", t)).unwrap_or_default().yellow()
+                );
+                if !s.participants.is_empty() {
+                    println!("      with: {}", s.participants.join(", "));
+                }
+                if !s.outcome.is_empty() {
+                    println!("      outcome: {}", s.outcome.green());
+                }
+                if !s.notes.is_empty() {
+                    println!("      notes: {}", s.notes.italic());
+                }
+            }
+        }
+        SessionsCommand::Remove { id } => {
+            remove(db, *id)?.ok_or_else(|| KernelError::not_found("session", id.to_string()))?;
+            println!("{} session #{}", "Removed".red().bold(), id);
+        }
+    }
+    Ok(())
+}
+
+// --- API: /sessions ---
+
+// POST /sessions
+pub async fn api_log(
+    State(db): State<Arc<Database>>,
+    body: Result<Json<NewSession>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(new) = body?;
+    let session = log(&db, new)?;
+    Ok((StatusCode::CREATED, Json(session)).into_response())
+}
+
+// GET /sessions?ritual=&team=
+pub async fn api_list(
+    State(db): State<Arc<Database>>,
+    Query(filter): Query<SessionFilter>,
+) -> Result<Response, KernelError> {
+    Ok(Json(list(&db, &filter)?).into_response())
+}
+
+// GET /sessions/{id}
+pub async fn api_get(State(db): State<Arc<Database>>, Path(id): Path<u64>) -> Result<Response, KernelError> {
+    let session = get(&db, id)?.ok_or_else(|| KernelError::not_found("session", id.to_string()))?;
+    Ok(Json(session).into_response())
+}
+
+// DELETE /sessions/{id}
+pub async fn api_remove(State(db): State<Arc<Database>>, Path(id): Path<u64>) -> Result<Response, KernelError> {
+    let session = remove(&db, id)?.ok_or_else(|| KernelError::not_found("session", id.to_string()))?;
+    Ok(Json(session).into_response())
+}
+
+// GET /rituals/{id}/sessions
+pub async fn api_for_ritual(
+    State(db): State<Arc<Database>>,
+    Path(id): Path<String>,
+) -> Result<Response, KernelError> {
+    if load_ritual(&db, &id)?.is_none() {
+        return Err(KernelError::not_found("ritual", &id));
+    }
+    let filter = SessionFilter { ritual: Some(id), team: None };
+    Ok(Json(list(&db, &filter)?).into_response())
+}
+
+// GET /teams/{team}/sessions
+pub async fn api_for_team(
+    State(db): State<Arc<Database>>,
+    Path(team): Path<String>,
+) -> Result<Response, KernelError> {
+    teams::require_team(&db, &team)?;
+    let filter = SessionFilter { ritual: None, team: Some(team) };
+    Ok(Json(list(&db, &filter)?).into_response())
+}
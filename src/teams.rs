@@ -0,0 +1,590 @@
+// --- TEAMS & ADOPTION TRACKING ---
+// Teams are stored in TEAMS_TABLE; ADOPTIONS_TABLE records which ritual each team
+// has installed, who owns it there and whether it is on trial, active or retired.
+// Together they answer "which protocols are actually running, and where?".
+
+use axum::{
+    extract::{rejection::JsonRejection, Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use clap::{Subcommand, ValueEnum};
+use colored::*;
+use redb::{Database, ReadableTable, TableDefinition};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::error::KernelError;
+use crate::{load_ritual, load_rituals, RitualFilter};
+
+// team id -> Team JSON
+const TEAMS_TABLE: TableDefinition<&str, &str> = TableDefinition::new("teams");
+// (team id, ritual id) -> Adoption JSON
+const ADOPTIONS_TABLE: TableDefinition<(&str, &str), &str> = TableDefinition::new("adoptions");
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Team {
+    id: String,
+    name: String,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum AdoptionStatus {
+    /// Being tried out; may still be dropped
+    Trial,
+    /// Part of how the team works
+    Active,
+    /// No longer practised (kept for history)
+    Retired,
+}
+
+impl AdoptionStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            AdoptionStatus::Trial => "trial",
+            AdoptionStatus::Active => "active",
+            AdoptionStatus::Retired => "retired",
+        }
+    }
+
+    fn colored(self) -> ColoredString {
+        match self {
+            AdoptionStatus::Trial => self.as_str().yellow(),
+            AdoptionStatus::Active => self.as_str().green(),
+            AdoptionStatus::Retired => self.as_str().dimmed(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Adoption {
+    team: String,
+    ritual_id: String,
+    owner: String,
+    status: AdoptionStatus,
+    installed_at: DateTime<Utc>,
+    status_changed_at: DateTime<Utc>,
+}
+
+// An adoption as listed by the API, with the ritual's current name (None if it was deleted)
+#[derive(Debug, Serialize)]
+pub struct AdoptionView {
+    #[serde(flatten)]
+    adoption: Adoption,
+    ritual_name: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TeamSummary {
+    #[serde(flatten)]
+    team: Team,
+    // Number of adoptions per status
+    rituals: BTreeMap<&'static str, usize>,
+}
+
+// Team ids appear in URLs: lowercase letters, digits and dashes
+fn validate_team_id(id: &str) -> Result<(), KernelError> {
+    let valid = !id.is_empty()
+        && id.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if valid {
+        Ok(())
+    } else {
+        Err(KernelError::Validation(vec![format!(
+            "team id '{}' must be non-empty and use only a-z, 0-9 and '-'",
+            id
+        )]))
+    }
+}
+
+fn validate_owner(owner: &str) -> Result<(), KernelError> {
+    if owner.trim().is_empty() {
+        return Err(KernelError::Validation(vec!["owner must not be empty".to_string()]));
+    }
+    Ok(())
+}
+
+pub fn get_team(db: &Database, id: &str) -> Result<Option<Team>, KernelError> {
+    let read_txn = db.begin_read()?;
+    let table = match read_txn.open_table(TEAMS_TABLE) {
+        Ok(t) => t,
+        Err(redb::TableError::TableDoesNotExist(_)) => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let team = match table.get(id)? {
+        Some(v) => Some(serde_json::from_str(v.value())?),
+        None => None,
+    };
+    Ok(team)
+}
+
+pub fn require_team(db: &Database, id: &str) -> Result<Team, KernelError> {
+    get_team(db, id)?.ok_or_else(|| KernelError::not_found("team", id))
+}
+
+pub fn list_teams(db: &Database) -> Result<Vec<Team>, KernelError> {
+    let read_txn = db.begin_read()?;
+    let table = match read_txn.open_table(TEAMS_TABLE) {
+        Ok(t) => t,
+        Err(redb::TableError::TableDoesNotExist(_)) => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut teams = Vec::new();
+    for item in table.iter()? {
+        let (_, value) = item?;
+        teams.push(serde_json::from_str(value.value())?);
+    }
+    Ok(teams)
+}
+
+pub fn create_team(db: &Database, id: &str, name: Option<&str>) -> Result<Team, KernelError> {
+    validate_team_id(id)?;
+    let team = Team {
+        id: id.to_string(),
+        name: name.filter(|n| !n.trim().is_empty()).unwrap_or(id).to_string(),
+        created_at: Utc::now(),
+    };
+
+    let write_txn = db.begin_write()?;
+    {
+        let mut table = write_txn.open_table(TEAMS_TABLE)?;
+        if table.get(id)?.is_some() {
+            return Err(KernelError::Conflict(format!("A team with id '{}' already exists", id)));
+        }
+        let json = serde_json::to_string(&team)?;
+        table.insert(id, json.as_str())?;
+    }
+    write_txn.commit()?;
+    Ok(team)
+}
+
+// Removes a team together with its adoption records
+pub fn delete_team(db: &Database, id: &str) -> Result<Option<Team>, KernelError> {
+    let write_txn = db.begin_write()?;
+    let removed = {
+        let mut teams = write_txn.open_table(TEAMS_TABLE)?;
+        let removed: Option<Team> = match teams.remove(id)? {
+            Some(v) => Some(serde_json::from_str(v.value())?),
+            None => None,
+        };
+
+        let mut adoptions = write_txn.open_table(ADOPTIONS_TABLE)?;
+        let mut keys = Vec::new();
+        for item in adoptions.range((id, "")..)? {
+            let (key, _) = item?;
+            let (team, ritual) = key.value();
+            if team != id {
+                break;
+            }
+            keys.push(ritual.to_string());
+        }
+        for ritual in &keys {
+            adoptions.remove((id, ritual.as_str()))?;
+        }
+        removed
+    };
+    write_txn.commit()?;
+    Ok(removed)
+}
+
+// Adoption records, optionally for one team only
+pub fn adoptions(db: &Database, team: Option<&str>) -> Result<Vec<Adoption>, KernelError> {
+    let read_txn = db.begin_read()?;
+    let table = match read_txn.open_table(ADOPTIONS_TABLE) {
+        Ok(t) => t,
+        Err(redb::TableError::TableDoesNotExist(_)) => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut adoptions = Vec::new();
+    for item in table.iter()? {
+        let (key, value) = item?;
+        if team.is_some_and(|t| t != key.value().0) {
+            continue;
+        }
+        adoptions.push(serde_json::from_str(value.value())?);
+    }
+    Ok(adoptions)
+}
+
+fn put_adoption(table: &mut redb::Table<(&str, &str), &str>, adoption: &Adoption) -> Result<(), KernelError> {
+    let json = serde_json::to_string(adoption)?;
+    table.insert((adoption.team.as_str(), adoption.ritual_id.as_str()), json.as_str())?;
+    Ok(())
+}
+
+pub fn adopt(
+    db: &Database,
+    team: &str,
+    ritual_id: &str,
+    owner: &str,
+    status: AdoptionStatus,
+) -> Result<Adoption, KernelError> {
+    validate_owner(owner)?;
+    if load_ritual(db, ritual_id)?.is_none() {
+        return Err(KernelError::not_found("ritual", ritual_id));
+    }
+
+    // Check and insert in one transaction so concurrent adopts cannot both succeed
+    let write_txn = db.begin_write()?;
+    let adoption = {
+        if write_txn.open_table(TEAMS_TABLE)?.get(team)?.is_none() {
+            return Err(KernelError::not_found("team", team));
+        }
+        let mut table = write_txn.open_table(ADOPTIONS_TABLE)?;
+        if table.get((team, ritual_id))?.is_some() {
+            return Err(KernelError::Conflict(format!(
+                "Team '{}' has already adopted '{}'; change its status instead",
+                team, ritual_id
+            )));
+        }
+
+        let now = Utc::now();
+        let adoption = Adoption {
+            team: team.to_string(),
+            ritual_id: ritual_id.to_string(),
+            owner: owner.trim().to_string(),
+            status,
+            installed_at: now,
+            status_changed_at: now,
+        };
+        put_adoption(&mut table, &adoption)?;
+        adoption
+    };
+    write_txn.commit()?;
+    Ok(adoption)
+}
+
+pub fn update_adoption(
+    db: &Database,
+    team: &str,
+    ritual_id: &str,
+    status: Option<AdoptionStatus>,
+    owner: Option<&str>,
+) -> Result<Adoption, KernelError> {
+    if let Some(owner) = owner {
+        validate_owner(owner)?;
+    }
+
+    let write_txn = db.begin_write()?;
+    let adoption = {
+        let mut table = write_txn.open_table(ADOPTIONS_TABLE)?;
+        let stored = table.get((team, ritual_id))?.map(|v| v.value().to_string());
+        let mut adoption: Adoption = match stored {
+            Some(json) => serde_json::from_str(&json)?,
+            None => return Err(KernelError::not_found("adoption", format!("{}/{}", team, ritual_id))),
+        };
+
+        if let Some(owner) = owner {
+            adoption.owner = owner.trim().to_string();
+        }
+        if let Some(status) = status.filter(|s| *s != adoption.status) {
+            adoption.status = status;
+            adoption.status_changed_at = Utc::now();
+        }
+        put_adoption(&mut table, &adoption)?;
+        adoption
+    };
+    write_txn.commit()?;
+    Ok(adoption)
+}
+
+pub fn remove_adoption(db: &Database, team: &str, ritual_id: &str) -> Result<Option<Adoption>, KernelError> {
+    let write_txn = db.begin_write()?;
+    let removed = {
+        let mut table = write_txn.open_table(ADOPTIONS_TABLE)?;
+        let removed = match table.remove((team, ritual_id))? {
+            Some(v) => Some(serde_json::from_str(v.value())?),
+            None => None,
+        };
+        removed
+    };
+    write_txn.commit()?;
+    Ok(removed)
+}
+
+fn summarize(team: Team, adoptions: &[Adoption]) -> TeamSummary {
+    let mut rituals = BTreeMap::new();
+    for adoption in adoptions.iter().filter(|a| a.team == team.id) {
+        *rituals.entry(adoption.status.as_str()).or_insert(0) += 1;
+    }
+    TeamSummary { team, rituals }
+}
+
+fn with_names(db: &Database, adoptions: Vec<Adoption>) -> Result<Vec<AdoptionView>, KernelError> {
+    let names: BTreeMap<String, String> = load_rituals(db, &RitualFilter::default())?
+        .into_iter()
+        .map(|r| (r.id, r.name))
+        .collect();
+    Ok(adoptions
+        .into_iter()
+        .map(|adoption| AdoptionView { ritual_name: names.get(&adoption.ritual_id).cloned(), adoption })
+        .collect())
+}
+
+// --- CLI: `culture-kernel teams` ---
+
+#[derive(Subcommand, Debug)]
+pub enum TeamsCommand {
+    /// List teams with their adoption counts
+    List,
+    /// Register a team
+    Add {
+        /// Team id, e.g. platform-squad
+        id: String,
+        /// Display name (defaults to the id)
+        #[arg(long)]
+        name: Option<String>,
+    },
+    /// Delete a team and its adoption records
+    Remove { id: String },
+    /// Show the rituals a team has adopted
+    Show { id: String },
+    /// Record that a team installed a ritual
+    Adopt {
+        team: String,
+        /// Ritual id, e.g. AKAN_10_SANKOFA
+        ritual: String,
+        /// Person accountable for running the ritual in this team
+        #[arg(long)]
+        owner: String,
+        #[arg(long, value_enum, default_value_t = AdoptionStatus::Trial)]
+        status: AdoptionStatus,
+    },
+    /// Change the status (or owner) of an adoption
+    Status {
+        team: String,
+        ritual: String,
+        #[arg(value_enum)]
+        status: AdoptionStatus,
+        /// Hand the ritual over to a new owner at the same time
+        #[arg(long)]
+        owner: Option<String>,
+    },
+    /// Delete an adoption record (use `status ... retired` to keep the history)
+    Drop { team: String, ritual: String },
+    /// Which rituals are running where, across every team
+    Overview,
+}
+
+pub fn teams_cli(db: &Arc<Database>, command: &TeamsCommand) -> anyhow::Result<()> {
+    match command {
+        TeamsCommand::List => {
+            let adoptions = adoptions(db, None)?;
+            let teams = list_teams(db)?;
+            if teams.is_empty() {
+                println!("No teams yet. Add one with 'culture-kernel teams add <id>'.");
+            }
+            for team in teams {
+                let summary = summarize(team, &adoptions);
+                let counts = [AdoptionStatus::Active, AdoptionStatus::Trial, AdoptionStatus::Retired]
+                    .iter()
+                    .map(|s| format!("{} {}", summary.rituals.get(s.as_str()).unwrap_or(&0), s.colored()))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                println!("{} {} ({})", summary.team.id.cyan().bold(), summary.team.name, counts);
+            }
+        }
+        TeamsCommand::Add { id, name } => {
+            let team = create_team(db, id, name.as_deref())?;
+            println!("{} team {} ({})", "Added".green().bold(), team.id.cyan(), team.name);
+        }
+        TeamsCommand::Remove { id } => {
+            delete_team(db, id)?.ok_or_else(|| KernelError::not_found("team", id))?;
+            println!("{} team {}", "Removed".red().bold(), id.cyan());
+        }
+        TeamsCommand::Show { id } => {
+            let team = require_team(db, id)?;
+            println!("{}", format!(" {} ({}) ", team.name.to_uppercase(), team.id).on_blue().white().bold());
+            let views = with_names(db, adoptions(db, Some(id))?)?;
+            if views.is_empty() {
+                println!("No rituals adopted yet.");
+            }
+            for view in views {
+                let a = &view.adoption;
+                println!(
+                    "  {:<8} {} {} owner: {}, since {}",
+                    a.status.colored(),
+                    a.ritual_id.cyan(),
+                    view.ritual_name.as_deref().unwrap_or("(ritual no longer exists)"),
+                    a.owner,
+                    a.status_changed_at.format("%Y-%m-%d")
+                );
+            }
+        }
+        TeamsCommand::Adopt { team, ritual, owner, status } => {
+            let adoption = adopt(db, team, ritual, owner, *status)?;
+            println!(
+                "{} {} adopted {} as {} (owner: {})",
+                "Recorded".green().bold(),
+                adoption.team.cyan(),
+                adoption.ritual_id.cyan(),
+                adoption.status.colored(),
+                adoption.owner
+            );
+        }
+        TeamsCommand::Status { team, ritual, status, owner } => {
+            let adoption = update_adoption(db, team, ritual, Some(*status), owner.as_deref())?;
+            println!(
+                "{} {} / {} is now {} (owner: {})",
+                "Updated".green().bold(),
+                adoption.team.cyan(),
+                adoption.ritual_id.cyan(),
+                adoption.status.colored(),
+                adoption.owner
+            );
+        }
+        TeamsCommand::Drop { team, ritual } => {
+            remove_adoption(db, team, ritual)?
+                .ok_or_else(|| KernelError::not_found("adoption", format!("{}/{}", team, ritual)))?;
+            println!("{} {} / {}", "Dropped".red().bold(), team.cyan(), ritual.cyan());
+        }
+        TeamsCommand::Overview => {
+            let mut by_ritual: BTreeMap<String, Vec<AdoptionView>> = BTreeMap::new();
+            for view in with_names(db, adoptions(db, None)?)? {
+                by_ritual.entry(view.adoption.ritual_id.clone()).or_default().push(view);
+            }
+            if by_ritual.is_empty() {
+                println!("No ritual has been adopted by any team yet.");
+            }
+            for (ritual_id, views) in by_ritual {
+                let name = views[0].ritual_name.clone().unwrap_or_default();
+                println!("{} {}", ritual_id.cyan().bold(), name.green());
+                for view in views {
+                    println!("    {:<8} {}", view.adoption.status.colored(), view.adoption.team);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// --- API: /teams, /adoptions ---
+
+#[derive(Debug, Deserialize)]
+pub struct NewTeam {
+    id: String,
+    name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NewAdoption {
+    ritual_id: String,
+    owner: String,
+    #[serde(default = "default_status")]
+    status: AdoptionStatus,
+}
+
+fn default_status() -> AdoptionStatus {
+    AdoptionStatus::Trial
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AdoptionChange {
+    status: Option<AdoptionStatus>,
+    owner: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AdoptionFilter {
+    status: Option<AdoptionStatus>,
+    ritual: Option<String>,
+}
+
+// GET /teams
+pub async fn api_list_teams(State(db): State<Arc<Database>>) -> Result<Response, KernelError> {
+    let adoptions = adoptions(&db, None)?;
+    let summaries: Vec<TeamSummary> = list_teams(&db)?
+        .into_iter()
+        .map(|team| summarize(team, &adoptions))
+        .collect();
+    Ok(Json(summaries).into_response())
+}
+
+// POST /teams
+pub async fn api_create_team(
+    State(db): State<Arc<Database>>,
+    body: Result<Json<NewTeam>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(new) = body?;
+    let team = create_team(&db, &new.id, new.name.as_deref())?;
+    Ok((StatusCode::CREATED, Json(team)).into_response())
+}
+
+// GET /teams/{team}
+pub async fn api_get_team(
+    State(db): State<Arc<Database>>,
+    Path(team): Path<String>,
+) -> Result<Response, KernelError> {
+    let team = require_team(&db, &team)?;
+    let adoptions = adoptions(&db, Some(&team.id))?;
+    Ok(Json(summarize(team, &adoptions)).into_response())
+}
+
+// DELETE /teams/{team}
+pub async fn api_delete_team(
+    State(db): State<Arc<Database>>,
+    Path(team): Path<String>,
+) -> Result<Response, KernelError> {
+    let removed = delete_team(&db, &team)?.ok_or_else(|| KernelError::not_found("team", &team))?;
+    Ok(Json(removed).into_response())
+}
+
+// GET /teams/{team}/rituals
+pub async fn api_team_rituals(
+    State(db): State<Arc<Database>>,
+    Path(team): Path<String>,
+) -> Result<Response, KernelError> {
+    require_team(&db, &team)?;
+    Ok(Json(with_names(&db, adoptions(&db, Some(&team))?)?).into_response())
+}
+
+// POST /teams/{team}/rituals
+pub async fn api_adopt(
+    State(db): State<Arc<Database>>,
+    Path(team): Path<String>,
+    body: Result<Json<NewAdoption>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(new) = body?;
+    let adoption = adopt(&db, &team, &new.ritual_id, &new.owner, new.status)?;
+    Ok((StatusCode::CREATED, Json(adoption)).into_response())
+}
+
+// PATCH /teams/{team}/rituals/{id}
+pub async fn api_update_adoption(
+    State(db): State<Arc<Database>>,
+    Path((team, ritual_id)): Path<(String, String)>,
+    body: Result<Json<AdoptionChange>, JsonRejection>,
+) -> Result<Response, KernelError> {
+    let Json(change) = body?;
+    let adoption = update_adoption(&db, &team, &ritual_id, change.status, change.owner.as_deref())?;
+    Ok(Json(adoption).into_response())
+}
+
+// DELETE /teams/{team}/rituals/{id}
+pub async fn api_remove_adoption(
+    State(db): State<Arc<Database>>,
+    Path((team, ritual_id)): Path<(String, String)>,
+) -> Result<Response, KernelError> {
+    let removed = remove_adoption(&db, &team, &ritual_id)?
+        .ok_or_else(|| KernelError::not_found("adoption", format!("{}/{}", team, ritual_id)))?;
+    Ok(Json(removed).into_response())
+}
+
+// GET /adoptions?status=active&ritual=AKAN_10_SANKOFA
+pub async fn api_adoptions(
+    State(db): State<Arc<Database>>,
+    Query(filter): Query<AdoptionFilter>,
+) -> Result<Response, KernelError> {
+    let adoptions: Vec<Adoption> = adoptions(&db, None)?
+        .into_iter()
+        .filter(|a| filter.status.is_none_or(|s| a.status == s))
+        .filter(|a| filter.ritual.as_deref().is_none_or(|r| a.ritual_id == r))
+        .collect();
+    Ok(Json(with_names(&db, adoptions)?).into_response())
+}